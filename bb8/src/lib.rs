@@ -0,0 +1,32 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod manager;
+
+pub use bb8;
+pub use deadpool::managed::reexports::*;
+
+pub use self::{
+    config::{Config, ConfigError},
+    manager::{Manager, ManagerConfig, RecyclingMethod},
+};
+
+/// Type alias for using [`deadpool::managed::Pool`] with [`bb8`].
+pub type Pool<M> = deadpool::managed::Pool<Manager<M>>;
+
+/// Type alias for using [`deadpool::managed::PoolBuilder`] with [`bb8`].
+pub type PoolBuilder<M> = deadpool::managed::PoolBuilder<Manager<M>>;
+
+/// Type alias for using [`deadpool::managed::BuildError`] with [`bb8`].
+pub type BuildError = deadpool::managed::BuildError;
+
+/// Type alias for using [`deadpool::managed::CreatePoolError`] with
+/// [`bb8`].
+pub type CreatePoolError = deadpool::managed::CreatePoolError<ConfigError>;
+
+/// Type alias for using [`deadpool::managed::PoolError`] with [`bb8`].
+pub type PoolError<M> = deadpool::managed::PoolError<<M as bb8::ManageConnection>::Error>;
+
+/// Type alias for using [`deadpool::managed::Object`] with [`bb8`].
+pub type Connection<M> = deadpool::managed::Object<Manager<M>>;
@@ -0,0 +1,189 @@
+use std::fmt;
+
+use bb8::ManageConnection;
+use deadpool::managed::{self, Metrics, RecycleError, RecycleResult};
+
+/// [`Manager`] which creates and recycles connections using a
+/// [`bb8::ManageConnection`].
+///
+/// See the [`deadpool` documentation](deadpool) for usage examples.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager<M> {
+    manager: M,
+    config: ManagerConfig,
+}
+
+// Implemented manually as `ManageConnection` doesn't require `Debug`.
+impl<M> fmt::Debug for Manager<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> Manager<M> {
+    /// Creates a new [`Manager`] wrapping the given bb8 `manager` using the
+    /// default [`ManagerConfig`].
+    #[must_use]
+    pub fn new(manager: M) -> Self {
+        Self::from_config(manager, ManagerConfig::default())
+    }
+
+    /// Creates a new [`Manager`] wrapping the given bb8 `manager` using the
+    /// provided [`ManagerConfig`].
+    #[must_use]
+    pub fn from_config(manager: M, config: ManagerConfig) -> Self {
+        Self { manager, config }
+    }
+
+    /// Returns the wrapped bb8 manager.
+    #[must_use]
+    pub fn inner(&self) -> &M {
+        &self.manager
+    }
+}
+
+impl<M: ManageConnection> managed::Manager for Manager<M> {
+    type Type = M::Connection;
+    type Error = M::Error;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        self.manager.connect().await
+    }
+
+    async fn recycle(&self, conn: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
+        if self.manager.has_broken(conn) {
+            return Err(RecycleError::Message(
+                "Connection is broken. Connection is considered unusable.".into(),
+            ));
+        }
+        match self.config.recycling_method {
+            RecyclingMethod::Fast => Ok(()),
+            RecyclingMethod::Verified => self
+                .manager
+                .is_valid(conn)
+                .await
+                .map_err(RecycleError::Backend),
+        }
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+///
+/// [`Pool`]: crate::Pool
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which matches the `test_on_check_out`
+/// default of bb8.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Only run [`ManageConnection::has_broken()`] when recycling existing
+    /// connections.
+    Fast,
+
+    /// Run [`ManageConnection::has_broken()`] followed by
+    /// [`ManageConnection::is_valid()`] when recycling existing
+    /// connections.
+    #[default]
+    Verified,
+}
+
+#[cfg(all(test, feature = "rt_tokio_1"))]
+mod tests {
+    use std::{
+        io,
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    };
+
+    use crate::{Config, Pool, PoolConfig, Runtime};
+
+    use super::*;
+
+    /// In-memory bb8 manager whose connections are numbered in the order
+    /// they were created.
+    #[derive(Default)]
+    struct TestManager {
+        connects: AtomicUsize,
+        validations: AtomicUsize,
+        broken: AtomicBool,
+    }
+
+    impl ManageConnection for TestManager {
+        type Connection = usize;
+        type Error = io::Error;
+
+        async fn connect(&self) -> Result<usize, io::Error> {
+            Ok(self.connects.fetch_add(1, Ordering::Relaxed))
+        }
+
+        async fn is_valid(&self, _: &mut usize) -> Result<(), io::Error> {
+            let _ = self.validations.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn has_broken(&self, _: &mut usize) -> bool {
+            self.broken.load(Ordering::Relaxed)
+        }
+    }
+
+    fn pool(recycling_method: RecyclingMethod) -> Pool<TestManager> {
+        let config = Config {
+            manager: Some(ManagerConfig { recycling_method }),
+            pool: Some(PoolConfig::new(1)),
+        };
+        config
+            .create_pool(TestManager::default(), Some(Runtime::Tokio1))
+            .unwrap()
+    }
+
+    async fn get(pool: &Pool<TestManager>) -> usize {
+        *pool.get().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn fast_recycling_skips_is_valid() {
+        let pool = pool(RecyclingMethod::Fast);
+        for _ in 0..3 {
+            assert_eq!(get(&pool).await, 0);
+        }
+        let manager = pool.manager().inner();
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn verified_recycling_runs_is_valid() {
+        let pool = pool(RecyclingMethod::Verified);
+        for _ in 0..3 {
+            assert_eq!(get(&pool).await, 0);
+        }
+        let manager = pool.manager().inner();
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn broken_connections_are_replaced() {
+        let pool = pool(RecyclingMethod::Verified);
+        assert_eq!(get(&pool).await, 0);
+        pool.manager().inner().broken.store(true, Ordering::Relaxed);
+        assert_eq!(get(&pool).await, 1);
+        let manager = pool.manager().inner();
+        assert_eq!(manager.connects.load(Ordering::Relaxed), 2);
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,264 @@
+use std::{collections::HashMap, fmt, time::Duration};
+
+#[cfg(feature = "tls")]
+use crate::TlsConfig;
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Default port of the ClickHouse native protocol.
+pub const DEFAULT_PORT: u16 = 9000;
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// CLICKHOUSE__HOST=clickhouse.example.com
+/// CLICKHOUSE__USER=default
+/// CLICKHOUSE__PASSWORD=topsecret
+/// CLICKHOUSE__DATABASE=analytics
+/// CLICKHOUSE__SETTINGS__MAX_THREADS=8
+/// CLICKHOUSE__SETTINGS__ASYNC_INSERT=1
+/// CLICKHOUSE__POOL__MAX_SIZE=8
+/// CLICKHOUSE__POOL__TIMEOUTS__WAIT__SECS=5
+/// CLICKHOUSE__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     clickhouse: deadpool_clickhouse::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Host of the ClickHouse server.
+    pub host: Option<String>,
+
+    /// Port of the native protocol.
+    ///
+    /// Defaults to [`DEFAULT_PORT`].
+    pub port: Option<u16>,
+
+    /// User to authenticate as.
+    ///
+    /// Defaults to `default`.
+    pub user: Option<String>,
+
+    /// Password of the [`Config::user`].
+    pub password: Option<String>,
+
+    /// Default database of new connections.
+    pub database: Option<String>,
+
+    /// Settings applied to every new connection using `SET` statements,
+    /// e.g. `max_threads` or `async_insert`.
+    ///
+    /// The values are sent as string literals which ClickHouse converts to
+    /// the type of the setting.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub settings: HashMap<String, String>,
+
+    /// Timeout for establishing a connection including the handshake and
+    /// applying the [`Config::settings`].
+    pub connect_timeout: Option<Duration>,
+
+    /// TLS configuration.
+    ///
+    /// Connections are not encrypted if this is not set.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the server at the given `host`.
+    #[must_use]
+    pub fn from_host<T: Into<String>>(host: T) -> Self {
+        Self {
+            host: Some(host.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the configured [`Config::port`] or [`DEFAULT_PORT`].
+    #[must_use]
+    pub fn get_port(&self) -> u16 {
+        self.port.unwrap_or(DEFAULT_PORT)
+    }
+
+    /// Returns the `SET` statements applying the [`Config::settings`] in the
+    /// order of their names.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidSettingName`] if a name contains
+    /// anything but ASCII letters, digits and underscores.
+    pub fn get_set_statements(&self) -> Result<Vec<String>, ConfigError> {
+        let mut settings = self.settings.iter().collect::<Vec<_>>();
+        settings.sort_unstable();
+        settings
+            .into_iter()
+            .map(|(name, value)| {
+                let valid =
+                    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid {
+                    return Err(ConfigError::InvalidSettingName(name.clone()));
+                }
+                let value = value.replace('\\', "\\\\").replace('\'', "\\'");
+                Ok(format!("SET {name} = '{value}'"))
+            })
+            .collect()
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the ClickHouse
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `InvalidSettingName` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::host`] was specified.
+    MissingHost,
+
+    /// The name of one of the [`Config::settings`] is invalid.
+    InvalidSettingName(String),
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHost => write!(f, "No host specified"),
+            Self::InvalidSettingName(name) => write!(f, "Invalid setting name `{name}`"),
+            #[cfg(feature = "tls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "tls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config<const N: usize>(settings: [(&str, &str); N]) -> Config {
+        Config {
+            settings: settings
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn statements_are_sorted_by_name() {
+        let config = config([("max_threads", "4"), ("async_insert", "1")]);
+        assert_eq!(
+            config.get_set_statements().unwrap(),
+            ["SET async_insert = '1'", "SET max_threads = '4'"]
+        );
+    }
+
+    #[test]
+    fn values_are_quoted() {
+        let config = config([("log_comment", r"it's a \ test")]);
+        assert_eq!(
+            config.get_set_statements().unwrap(),
+            [r"SET log_comment = 'it\'s a \\ test'"]
+        );
+    }
+
+    #[test]
+    fn quote_in_value_does_not_end_literal() {
+        let config = config([("log_comment", r"\'; DROP TABLE t; --")]);
+        assert_eq!(
+            config.get_set_statements().unwrap(),
+            [r"SET log_comment = '\\\'; DROP TABLE t; --'"]
+        );
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        for name in [
+            "",
+            "max threads",
+            "a=1; DROP TABLE t",
+            "max-threads",
+            "größe",
+        ] {
+            let config = config([(name, "1")]);
+            match config.get_set_statements() {
+                Err(ConfigError::InvalidSettingName(invalid)) => assert_eq!(invalid, name),
+                result => panic!("Unexpected result for `{name}`: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn valid_names_are_accepted() {
+        let config = config([("max_threads_2", "1"), ("_internal", "0")]);
+        assert!(config.get_set_statements().is_ok());
+    }
+
+    #[test]
+    fn no_settings_no_statements() {
+        assert!(Config::default().get_set_statements().unwrap().is_empty());
+    }
+}
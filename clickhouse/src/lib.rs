@@ -0,0 +1,173 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+#[cfg(feature = "tls")]
+mod tls;
+
+use std::{
+    fmt, io,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use deadpool::managed::{self, RecycleError};
+use klickhouse::{Client, ClientOptions, KlickhouseError};
+use tokio::time::timeout;
+
+pub use klickhouse;
+
+pub use self::config::{Config, ConfigError, DEFAULT_PORT};
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub use self::tls::TlsConfig;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "klickhouse",
+    Manager,
+    managed::Object<Manager>,
+    KlickhouseError,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<KlickhouseError>;
+
+/// [`Manager`] for creating and recycling ClickHouse [`Connection`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    host: String,
+    port: u16,
+    options: ClientOptions,
+    set_statements: Vec<String>,
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "tls")]
+    tls: Option<(
+        tokio_rustls::TlsConnector,
+        tokio_rustls::rustls::pki_types::ServerName<'static>,
+    )>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let host = config.host.clone().ok_or(ConfigError::MissingHost)?;
+        let mut options = ClientOptions::default();
+        if let Some(user) = &config.user {
+            options.username.clone_from(user);
+        }
+        if let Some(password) = &config.password {
+            options.password.clone_from(password);
+        }
+        if let Some(database) = &config.database {
+            options.default_database.clone_from(database);
+        }
+        Ok(Self {
+            #[cfg(feature = "tls")]
+            tls: config
+                .tls
+                .as_ref()
+                .map(|tls| tls.connector(&host))
+                .transpose()?,
+            host,
+            port: config.get_port(),
+            options,
+            set_statements: config.get_set_statements()?,
+            connect_timeout: config.connect_timeout,
+        })
+    }
+
+    async fn connect(&self) -> Result<Client, KlickhouseError> {
+        let addr = (self.host.as_str(), self.port);
+        #[cfg(feature = "tls")]
+        if let Some((connector, server_name)) = &self.tls {
+            return Client::connect_tls(addr, self.options.clone(), server_name.clone(), connector)
+                .await;
+        }
+        Client::connect(addr, self.options.clone()).await
+    }
+
+    async fn connect_and_apply_settings(&self) -> Result<Client, KlickhouseError> {
+        let client = self.connect().await?;
+        for statement in &self.set_statements {
+            client.execute(statement).await?;
+        }
+        Ok(client)
+    }
+}
+
+// Implemented manually to not leak the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("user", &self.options.username)
+            .field("database", &self.options.default_database)
+            .field("set_statements", &self.set_statements)
+            .field("connect_timeout", &self.connect_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = KlickhouseError;
+
+    async fn create(&self) -> Result<Connection, KlickhouseError> {
+        let connect = self.connect_and_apply_settings();
+        let client = match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, connect)
+                .await
+                .map_err(|_| KlickhouseError::Io(io::ErrorKind::TimedOut.into()))??,
+            None => connect.await?,
+        };
+        Ok(Connection { client })
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        if conn.is_closed() {
+            return Err(RecycleError::message("Connection closed"));
+        }
+        // The native protocol client doesn't expose the `Ping` packet so a
+        // trivial query is used instead.
+        conn.execute("SELECT 1").await?;
+        Ok(())
+    }
+}
+
+/// Wrapper around [`Client`] which implements [`Debug`](fmt::Debug).
+///
+/// Every [`Connection`] is a separate ClickHouse session, so `SET`
+/// statements executed on it stay in effect until it is closed.
+pub struct Connection {
+    client: Client,
+}
+
+// Implemented manually as `Client` doesn't implement `Debug`.
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("closed", &self.client.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Connection {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+}
@@ -0,0 +1,38 @@
+use std::{path::PathBuf, sync::Arc};
+
+use tokio_rustls::{rustls::pki_types::ServerName, TlsConnector};
+
+use crate::ConfigError;
+
+/// TLS configuration.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the server.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<PathBuf>,
+
+    /// Name used for verifying the server certificate.
+    ///
+    /// Defaults to [`Config::host`](crate::Config::host).
+    pub domain_name: Option<String>,
+}
+
+impl TlsConfig {
+    /// Creates the [`TlsConnector`] and the [`ServerName`] used for
+    /// verifying the certificate of the given `host`.
+    pub(crate) fn connector(
+        &self,
+        host: &str,
+    ) -> Result<(TlsConnector, ServerName<'static>), ConfigError> {
+        let config = deadpool_rustls::client_config(self.ca_file.as_deref()).map_err(tls_error)?;
+        let domain_name = self.domain_name.as_deref().unwrap_or(host);
+        let server_name = ServerName::try_from(domain_name.to_owned()).map_err(tls_error)?;
+        Ok((TlsConnector::from(Arc::new(config)), server_name))
+    }
+}
+
+fn tls_error(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> ConfigError {
+    ConfigError::Tls(e.into())
+}
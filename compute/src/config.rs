@@ -0,0 +1,91 @@
+use std::convert::Infallible;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig};
+
+/// Configuration object.
+///
+/// The [`Manager`] itself is not part of the configuration as it contains
+/// the factory function and is passed to [`Config::create_pool()`]
+/// instead.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// COMPUTE__POOL__MAX_SIZE=4
+/// COMPUTE__POOL__TIMEOUTS__WAIT__SECS=5
+/// COMPUTE__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     compute: deadpool_compute::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// [`Pool`] configuration.
+    ///
+    /// The `max_size` bounds the number of objects and therefore the number
+    /// of concurrent computations.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Pool`] for the given [`Manager`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool<T: Send + 'static>(
+        &self,
+        manager: Manager<T>,
+    ) -> Result<Pool<T>, CreatePoolError> {
+        self.builder(manager)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] for the given [`Manager`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder<T: Send + 'static>(
+        &self,
+        manager: Manager<T>,
+    ) -> Result<PoolBuilder<T>, ConfigError> {
+        let runtime = manager.runtime();
+        Ok(Pool::builder(manager)
+            .config(self.get_pool_config())
+            .runtime(runtime))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the
+/// configuration.
+///
+/// This is just a type alias to [`Infallible`] at the moment as there
+/// is no validation happening at the configuration phase.
+pub type ConfigError = Infallible;
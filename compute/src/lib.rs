@@ -0,0 +1,40 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod manager;
+
+use std::convert::Infallible;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+
+pub use self::{
+    config::{Config, ConfigError},
+    manager::Manager,
+};
+
+/// Type alias for using [`deadpool::managed::Pool`] with compute objects.
+pub type Pool<T> = deadpool::managed::Pool<Manager<T>>;
+
+/// Type alias for using [`deadpool::managed::PoolBuilder`] with compute
+/// objects.
+pub type PoolBuilder<T> = deadpool::managed::PoolBuilder<Manager<T>>;
+
+/// Type alias for using [`deadpool::managed::BuildError`] with compute
+/// objects.
+pub type BuildError = deadpool::managed::BuildError;
+
+/// Type alias for using [`deadpool::managed::CreatePoolError`] with compute
+/// objects.
+pub type CreatePoolError = deadpool::managed::CreatePoolError<ConfigError>;
+
+/// Type alias for using [`deadpool::managed::PoolError`] with compute
+/// objects.
+///
+/// As creating objects can't fail this only occurs on timeouts or when the
+/// [`Pool`] is closed.
+pub type PoolError = deadpool::managed::PoolError<Infallible>;
+
+/// Type alias for using [`deadpool::managed::Object`] with compute objects.
+pub type Object<T> = deadpool::managed::Object<Manager<T>>;
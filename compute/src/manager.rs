@@ -0,0 +1,185 @@
+use std::{convert::Infallible, fmt, sync::Arc};
+
+use deadpool::{
+    managed::{self, Metrics, RecycleError, RecycleResult},
+    Runtime,
+};
+use deadpool_sync::SyncWrapper;
+
+type Factory<T> = Arc<dyn Fn() -> T + Send + Sync>;
+type WarmUp<T> = Arc<dyn Fn(&mut T) + Send + Sync>;
+
+/// [`Manager`] which creates objects by calling a factory function on the
+/// blocking thread pool of the [`Runtime`].
+///
+/// Creating an object can't fail. Loading a model or compiling templates
+/// that could fail should be done upfront, e.g. by loading the bytes of the
+/// model once and letting the factory only build sessions from them.
+///
+/// See the [`deadpool` documentation](deadpool) for usage examples.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager<T> {
+    factory: Factory<T>,
+    warm_up: Option<WarmUp<T>>,
+    runtime: Runtime,
+}
+
+// Implemented manually as the factory and warm-up functions don't implement
+// `Debug`.
+impl<T> fmt::Debug for Manager<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("warm_up", &self.warm_up.is_some())
+            .field("runtime", &self.runtime)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Manager<T> {
+    /// Creates a new [`Manager`] which creates objects using the given
+    /// `factory` on the blocking thread pool of the given [`Runtime`].
+    #[must_use]
+    pub fn new<F>(factory: F, runtime: Runtime) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            factory: Arc::new(factory),
+            warm_up: None,
+            runtime,
+        }
+    }
+
+    /// Sets a function which is called with every newly created object
+    /// before it is added to the [`Pool`], e.g. to run a dummy inference
+    /// which initializes lazily allocated buffers.
+    ///
+    /// [`Pool`]: crate::Pool
+    #[must_use]
+    pub fn warm_up<W>(mut self, warm_up: W) -> Self
+    where
+        W: Fn(&mut T) + Send + Sync + 'static,
+    {
+        self.warm_up = Some(Arc::new(warm_up));
+        self
+    }
+
+    /// Returns the [`Runtime`] of this [`Manager`].
+    #[must_use]
+    pub fn runtime(&self) -> Runtime {
+        self.runtime
+    }
+}
+
+impl<T: Send + 'static> managed::Manager for Manager<T> {
+    type Type = SyncWrapper<T>;
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        let factory = self.factory.clone();
+        let warm_up = self.warm_up.clone();
+        SyncWrapper::new(self.runtime, move || {
+            let mut obj = factory();
+            if let Some(warm_up) = warm_up {
+                warm_up(&mut obj);
+            }
+            Ok(obj)
+        })
+        .await
+    }
+
+    async fn recycle(&self, obj: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
+        if obj.is_mutex_poisoned() {
+            return Err(RecycleError::Message(
+                "Mutex is poisoned. Object is considered unusable.".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "rt_tokio_1"))]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use crate::{Config, Pool, PoolConfig};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Object {
+        id: usize,
+        warm_ups: usize,
+    }
+
+    /// Creates a pool of the given `max_size` with objects numbered in the
+    /// order they were created and counts the warm-ups.
+    fn pool(max_size: usize) -> (Pool<Object>, Arc<AtomicUsize>) {
+        let created = Arc::new(AtomicUsize::new(0));
+        let warm_ups = Arc::new(AtomicUsize::new(0));
+        let counter = warm_ups.clone();
+        let manager = Manager::new(
+            move || Object {
+                id: created.fetch_add(1, Ordering::Relaxed),
+                warm_ups: 0,
+            },
+            Runtime::Tokio1,
+        )
+        .warm_up(move |obj| {
+            obj.warm_ups += 1;
+            let _ = counter.fetch_add(1, Ordering::Relaxed);
+        });
+        let config = Config {
+            pool: Some(PoolConfig::new(max_size)),
+        };
+        (config.create_pool(manager).unwrap(), warm_ups)
+    }
+
+    #[tokio::test]
+    async fn objects_are_warmed_up_once_and_reused() {
+        let (pool, warm_ups) = pool(1);
+        for _ in 0..3 {
+            let obj = pool.get().await.unwrap();
+            let (id, obj_warm_ups) = obj.interact(|obj| (obj.id, obj.warm_ups)).await.unwrap();
+            assert_eq!(id, 0);
+            assert_eq!(obj_warm_ups, 1);
+        }
+        assert_eq!(warm_ups.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn max_size_bounds_concurrent_interactions() {
+        let (pool, warm_ups) = pool(2);
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let pool = pool.clone();
+                let running = running.clone();
+                let max_running = max_running.clone();
+                tokio::spawn(async move {
+                    let obj = pool.get().await.unwrap();
+                    obj.interact(move |_| {
+                        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        let _ = max_running.fetch_max(now, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(20));
+                        let _ = running.fetch_sub(1, Ordering::SeqCst);
+                    })
+                    .await
+                    .unwrap();
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert!(max_running.load(Ordering::SeqCst) <= 2);
+        assert_eq!(pool.status().size, 2);
+        assert_eq!(warm_ups.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{fmt, path::PathBuf, time::Duration};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// COUCHBASE__CONNECTION_STRING=couchbases://cb.example.com
+/// COUCHBASE__BUCKET=travel-sample
+/// COUCHBASE__USERNAME=app
+/// COUCHBASE__PASSWORD=topsecret
+/// COUCHBASE__PING_SERVICES=kv,query
+/// COUCHBASE__POOL__MAX_SIZE=4
+/// COUCHBASE__POOL__TIMEOUTS__WAIT__SECS=5
+/// COUCHBASE__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     couchbase: deadpool_couchbase::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("couchbase.ping_services")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Connection string of the cluster, e.g. `couchbase://localhost`.
+    ///
+    /// `couchbases` connection strings encrypt the connections using TLS.
+    pub connection_string: Option<String>,
+
+    /// Name of the bucket every [`Connection`](crate::Connection) opens.
+    pub bucket: Option<String>,
+
+    /// Username used for authentication.
+    pub username: Option<String>,
+
+    /// Password used for authentication.
+    pub password: Option<String>,
+
+    /// Timeout for connecting to the cluster and waiting until the
+    /// [`Config::ping_services`] of the bucket are ready.
+    pub connect_timeout: Option<Duration>,
+
+    /// Services which are pinged before a connection is reused.
+    ///
+    /// Defaults to [`Service::Kv`] if empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ping_services: Vec<Service>,
+
+    /// Timeout of each ping of a [`Config::ping_services`] entry.
+    pub ping_timeout: Option<Duration>,
+
+    /// PEM file containing the CA certificates used to verify the servers.
+    ///
+    /// Defaults to the system root certificates and the Couchbase Capella
+    /// CA.
+    pub ca_file: Option<PathBuf>,
+
+    /// Disables the verification of the server certificates.
+    ///
+    /// This is insecure and should only be used for testing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accept_invalid_certs: bool,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `connection_string` and
+    /// `bucket`.
+    #[must_use]
+    pub fn new<S: Into<String>, B: Into<String>>(connection_string: S, bucket: B) -> Self {
+        Self {
+            connection_string: Some(connection_string.into()),
+            bucket: Some(bucket.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the [`Config::ping_services`] with [`Service::Kv`] used if
+    /// none are configured.
+    #[must_use]
+    pub fn get_ping_services(&self) -> Vec<Service> {
+        if self.ping_services.is_empty() {
+            vec![Service::Kv]
+        } else {
+            self.ping_services.clone()
+        }
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Couchbase service which can be pinged.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Service {
+    /// Key-value (data) service.
+    Kv,
+
+    /// SQL++ query service.
+    Query,
+
+    /// Full-text search service.
+    Search,
+}
+
+impl Service {
+    pub(crate) fn service_type(self) -> couchbase::service_type::ServiceType {
+        use couchbase::service_type::ServiceType;
+
+        match self {
+            Self::Kv => ServiceType::KV,
+            Self::Query => ServiceType::QUERY,
+            Self::Search => ServiceType::SEARCH,
+        }
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kv => write!(f, "kv"),
+            Self::Query => write!(f, "query"),
+            Self::Search => write!(f, "search"),
+        }
+    }
+}
+
+/// This error is returned if there is something wrong with the Couchbase
+/// configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// No [`Config::connection_string`] was specified.
+    MissingConnectionString,
+
+    /// No [`Config::bucket`] was specified.
+    MissingBucket,
+
+    /// [`Config::username`] or [`Config::password`] is missing.
+    MissingCredentials,
+
+    /// The [`Config::ca_file`] could not be read.
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingConnectionString => write!(f, "No connection string specified"),
+            Self::MissingBucket => write!(f, "No bucket specified"),
+            Self::MissingCredentials => write!(f, "Username and password must be configured"),
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
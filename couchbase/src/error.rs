@@ -0,0 +1,44 @@
+use std::fmt;
+
+use crate::Service;
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Connecting to the cluster, opening the bucket or pinging it failed.
+    Couchbase(couchbase::error::Error),
+
+    /// The bucket wasn't ready within the
+    /// [`Config::connect_timeout`](crate::Config::connect_timeout).
+    Timeout,
+
+    /// The ping report contains no healthy endpoint of the [`Service`].
+    Unavailable(Service),
+}
+
+impl From<couchbase::error::Error> for Error {
+    fn from(e: couchbase::error::Error) -> Self {
+        Self::Couchbase(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Couchbase(e) => write!(f, "Couchbase error: {e}"),
+            Self::Timeout => write!(f, "Timed out waiting for the bucket to become ready"),
+            Self::Unavailable(service) => {
+                write!(f, "No healthy endpoint for the {service} service")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Couchbase(e) => Some(e),
+            _ => None,
+        }
+    }
+}
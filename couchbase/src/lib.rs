@@ -0,0 +1,225 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+
+use std::{fmt, ops::Deref, time::Duration};
+
+use couchbase::{
+    authenticator::PasswordAuthenticator,
+    bucket::Bucket,
+    cluster::Cluster,
+    options::{
+        cluster_options::{ClusterOptions, TlsOptions},
+        diagnostic_options::{PingOptions, WaitUntilReadyOptions},
+    },
+    results::diagnostics::PingState,
+    service_type::ServiceType,
+};
+use deadpool::managed;
+use tokio::time::timeout;
+
+pub use couchbase;
+
+pub use self::{
+    config::{Config, ConfigError, Service},
+    error::Error,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "couchbase",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling Couchbase [`Connection`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    connection_string: String,
+    bucket: String,
+    options: ClusterOptions,
+    connect_timeout: Option<Duration>,
+    ping_services: Vec<Service>,
+    ping_options: PingOptions,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let connection_string = config
+            .connection_string
+            .clone()
+            .ok_or(ConfigError::MissingConnectionString)?;
+        let bucket = config.bucket.clone().ok_or(ConfigError::MissingBucket)?;
+        let (Some(username), Some(password)) = (&config.username, &config.password) else {
+            return Err(ConfigError::MissingCredentials);
+        };
+        let authenticator = PasswordAuthenticator::new(username.clone(), password.clone());
+        let options = ClusterOptions::new(authenticator.into()).tls_options(tls_options(config)?);
+        let ping_services = config.get_ping_services();
+        let mut ping_options = PingOptions::new().service_types(service_types(&ping_services));
+        if let Some(ping_timeout) = config.ping_timeout {
+            ping_options = ping_options
+                .kv_timeout(ping_timeout)
+                .query_timeout(ping_timeout)
+                .search_timeout(ping_timeout);
+        }
+        Ok(Self {
+            connection_string,
+            bucket,
+            options,
+            connect_timeout: config.connect_timeout,
+            ping_services,
+            ping_options,
+        })
+    }
+
+    /// Returns the name of the bucket opened by every [`Connection`].
+    #[must_use]
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    async fn connect(&self) -> Result<Connection, Error> {
+        let cluster = Cluster::connect(&self.connection_string, self.options.clone()).await?;
+        let bucket = cluster.bucket(self.bucket.clone());
+        let ready = WaitUntilReadyOptions::new().service_types(service_types(&self.ping_services));
+        bucket.wait_until_ready(ready).await?;
+        Ok(Connection { cluster, bucket })
+    }
+}
+
+// Implemented manually to not leak the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("connection_string", &self.connection_string)
+            .field("bucket", &self.bucket)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("ping_services", &self.ping_services)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, self.connect())
+                .await
+                .map_err(|_| Error::Timeout)?,
+            None => self.connect().await,
+        }
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        let report = conn
+            .bucket
+            .ping(self.ping_options.clone())
+            .await
+            .map_err(Error::from)?;
+        for service in &self.ping_services {
+            let healthy = report
+                .services
+                .get(&service.service_type())
+                .is_some_and(|endpoints| {
+                    endpoints
+                        .iter()
+                        .any(|endpoint| endpoint.state == PingState::Ok)
+                });
+            if !healthy {
+                return Err(Error::Unavailable(*service).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Handle of a Couchbase cluster and the configured bucket.
+///
+/// Every [`Connection`] has its own set of connections to the cluster.
+pub struct Connection {
+    cluster: Cluster,
+    bucket: Bucket,
+}
+
+impl Connection {
+    /// Returns the [`Cluster`] of this [`Connection`], e.g. for running
+    /// cluster level queries.
+    #[must_use]
+    pub fn cluster(&self) -> &Cluster {
+        &self.cluster
+    }
+
+    /// Returns the [`Bucket`] of this [`Connection`].
+    #[must_use]
+    pub fn bucket(&self) -> &Bucket {
+        &self.bucket
+    }
+}
+
+// Implemented manually as `Cluster` and `Bucket` don't implement `Debug`.
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("bucket", &self.bucket.name())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Connection {
+    type Target = Bucket;
+
+    fn deref(&self) -> &Bucket {
+        &self.bucket
+    }
+}
+
+fn service_types(services: &[Service]) -> Vec<ServiceType> {
+    services
+        .iter()
+        .map(|service| service.service_type())
+        .collect()
+}
+
+fn tls_options(config: &Config) -> Result<TlsOptions, ConfigError> {
+    let mut tls = TlsOptions::new();
+    if config.accept_invalid_certs {
+        tls = tls.danger_accept_invalid_certs(true);
+    }
+    let Some(ca_file) = &config.ca_file else {
+        return Ok(tls);
+    };
+    #[cfg(not(feature = "native-tls"))]
+    let certs = {
+        use rustls_pki_types::{pem::PemObject, CertificateDer};
+
+        CertificateDer::pem_file_iter(ca_file)
+            .map_err(tls_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(tls_error)?
+    };
+    #[cfg(feature = "native-tls")]
+    let certs = {
+        let pem = std::fs::read(ca_file).map_err(tls_error)?;
+        native_tls::Certificate::stack_from_pem(&pem).map_err(tls_error)?
+    };
+    Ok(tls.add_ca_certificates(certs))
+}
+
+fn tls_error(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> ConfigError {
+    ConfigError::Tls(e.into())
+}
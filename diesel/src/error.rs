@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Establishing a connection failed.
+    Connection(diesel::ConnectionError),
+
+    /// Verifying a connection using a ping query failed.
+    Ping(diesel::result::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connection(e) => write!(f, "Failed to establish connection: {e}"),
+            Self::Ping(e) => write!(f, "Failed to ping connection: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Connection(e) => Some(e),
+            Self::Ping(e) => Some(e),
+        }
+    }
+}
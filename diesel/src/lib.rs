@@ -0,0 +1,45 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+mod manager;
+
+#[cfg(feature = "mysql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+pub mod mysql;
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub mod postgres;
+#[cfg(feature = "sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+pub mod sqlite;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+pub use diesel;
+
+pub use self::{
+    config::{Config, ConfigError},
+    error::Error,
+    manager::{Manager, ManagerConfig, RecyclingMethod},
+};
+
+/// Type alias for using [`deadpool::managed::Pool`] with [`diesel`].
+pub type Pool<C> = deadpool::managed::Pool<Manager<C>>;
+
+/// Type alias for using [`deadpool::managed::PoolBuilder`] with [`diesel`].
+pub type PoolBuilder<C> = deadpool::managed::PoolBuilder<Manager<C>>;
+
+/// Type alias for using [`deadpool::managed::BuildError`] with [`diesel`].
+pub type BuildError = deadpool::managed::BuildError;
+
+/// Type alias for using [`deadpool::managed::CreatePoolError`] with
+/// [`diesel`].
+pub type CreatePoolError = deadpool::managed::CreatePoolError<ConfigError>;
+
+/// Type alias for using [`deadpool::managed::PoolError`] with [`diesel`].
+pub type PoolError = deadpool::managed::PoolError<Error>;
+
+/// Type alias for using [`deadpool::managed::Object`] with [`diesel`].
+pub type Connection<C> = deadpool::managed::Object<Manager<C>>;
@@ -0,0 +1,132 @@
+use std::{fmt, marker::PhantomData};
+
+use deadpool::{
+    managed::{self, Metrics, RecycleError, RecycleResult},
+    Runtime,
+};
+use deadpool_sync::SyncWrapper;
+use diesel::r2d2::R2D2Connection;
+
+use crate::Error;
+
+/// [`Connection`] [`Manager`] for use with [`diesel`].
+///
+/// See the [`deadpool` documentation](deadpool) for usage examples.
+///
+/// [`Connection`]: crate::Connection
+/// [`Manager`]: managed::Manager
+pub struct Manager<C> {
+    database_url: String,
+    runtime: Runtime,
+    config: ManagerConfig,
+    _marker: PhantomData<fn() -> C>,
+}
+
+// Implemented manually to avoid unnecessary trait bound on `C` type parameter.
+impl<C> fmt::Debug for Manager<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("database_url", &self.database_url)
+            .field("runtime", &self.runtime)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl<C> Manager<C> {
+    /// Creates a new [`Manager`] which establishes [`Connection`]s to the
+    /// given `database_url` using the default [`ManagerConfig`].
+    ///
+    /// [`Connection`]: crate::Connection
+    #[must_use]
+    pub fn new<S: Into<String>>(database_url: S, runtime: Runtime) -> Self {
+        Self::from_config(database_url, runtime, ManagerConfig::default())
+    }
+
+    /// Creates a new [`Manager`] which establishes [`Connection`]s to the
+    /// given `database_url` using the provided [`ManagerConfig`].
+    ///
+    /// [`Connection`]: crate::Connection
+    #[must_use]
+    pub fn from_config<S: Into<String>>(
+        database_url: S,
+        runtime: Runtime,
+        config: ManagerConfig,
+    ) -> Self {
+        Self {
+            database_url: database_url.into(),
+            runtime,
+            config,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> managed::Manager for Manager<C>
+where
+    C: R2D2Connection + 'static,
+{
+    type Type = SyncWrapper<C>;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        let database_url = self.database_url.clone();
+        SyncWrapper::new(self.runtime, move || {
+            C::establish(&database_url).map_err(Error::Connection)
+        })
+        .await
+    }
+
+    async fn recycle(&self, obj: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
+        if obj.is_mutex_poisoned() {
+            return Err(RecycleError::Message(
+                "Mutex is poisoned. Connection is considered unusable.".into(),
+            ));
+        }
+        let recycling_method = self.config.recycling_method;
+        obj.interact(move |conn| match recycling_method {
+            RecyclingMethod::Fast if conn.is_broken() => Err(RecycleError::Message(
+                "Connection is broken. Connection is considered unusable.".into(),
+            )),
+            RecyclingMethod::Fast => Ok(()),
+            RecyclingMethod::Verified => conn.ping().map_err(|e| Error::Ping(e).into()),
+        })
+        .await
+        .map_err(|e| RecycleError::message(format!("Panic: {e:?}")))?
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+///
+/// [`Pool`]: crate::Pool
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which runs a ping query before a connection
+/// is handed out.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Only run [`R2D2Connection::is_broken()`] when recycling existing
+    /// connections.
+    ///
+    /// This check is free of network I/O but won't detect connections
+    /// which were closed by the server.
+    Fast,
+
+    /// Run [`R2D2Connection::ping()`] when recycling existing connections.
+    #[default]
+    Verified,
+}
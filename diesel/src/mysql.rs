@@ -0,0 +1,19 @@
+//! Type aliases for using `deadpool-diesel` with MySQL.
+
+pub use diesel::mysql::MysqlConnection;
+
+/// Manager for MySQL connections
+pub type Manager = crate::Manager<MysqlConnection>;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+deadpool::managed_reexports!(
+    "diesel",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    crate::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
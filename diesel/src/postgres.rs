@@ -0,0 +1,19 @@
+//! Type aliases for using `deadpool-diesel` with PostgreSQL.
+
+pub use diesel::pg::PgConnection;
+
+/// Manager for PostgreSQL connections
+pub type Manager = crate::Manager<PgConnection>;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+deadpool::managed_reexports!(
+    "diesel",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    crate::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
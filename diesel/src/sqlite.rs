@@ -0,0 +1,19 @@
+//! Type aliases for using `deadpool-diesel` with SQLite.
+
+pub use diesel::sqlite::SqliteConnection;
+
+/// Manager for SQLite connections
+pub type Manager = crate::Manager<SqliteConnection>;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+deadpool::managed_reexports!(
+    "diesel",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    crate::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
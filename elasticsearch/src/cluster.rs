@@ -0,0 +1,60 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::{Object, Pool, PoolError};
+
+/// Multiple Elasticsearch or OpenSearch nodes with one [`Pool`] per node.
+///
+/// Requests are distributed across the nodes in a round-robin fashion.
+/// Nodes whose [`NodeHealth`](crate::NodeHealth) marks them as dead are
+/// skipped until their dead timeout expires. If all nodes are dead the next
+/// node in turn is used anyway which fails with [`Error::NodeDead`].
+///
+/// [`Error::NodeDead`]: crate::Error::NodeDead
+#[derive(Clone, Debug)]
+pub struct Cluster {
+    pools: Arc<[Pool]>,
+    next: Arc<AtomicUsize>,
+}
+
+impl Cluster {
+    /// Creates a new [`Cluster`] from the given pools.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pools` is empty.
+    #[must_use]
+    pub fn new(pools: Vec<Pool>) -> Self {
+        assert!(!pools.is_empty(), "A cluster needs at least one pool");
+        Self {
+            pools: pools.into(),
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the [`Pool`] of the next alive node.
+    pub fn pool(&self) -> &Pool {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        (0..self.pools.len())
+            .map(|offset| &self.pools[(start + offset) % self.pools.len()])
+            .find(|pool| pool.manager().health().is_alive())
+            .unwrap_or(&self.pools[start % self.pools.len()])
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the next alive node.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn get(&self) -> Result<Object, PoolError> {
+        self.pool().get().await
+    }
+
+    /// Returns the pools of all nodes of this [`Cluster`].
+    #[must_use]
+    pub fn pools(&self) -> &[Pool] {
+        &self.pools
+    }
+}
@@ -0,0 +1,255 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    Cluster, CreatePoolError, Credentials, Manager, Pool, PoolBuilder, PoolConfig, Runtime,
+};
+
+/// Default time a node is considered dead after a failed request.
+const DEFAULT_DEAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// ELASTICSEARCH__URLS=https://es1:9200,https://es2:9200
+/// ELASTICSEARCH__API_KEY=topsecret
+/// ELASTICSEARCH__POOL__MAX_SIZE=8
+/// ELASTICSEARCH__POOL__TIMEOUTS__WAIT__SECS=5
+/// ELASTICSEARCH__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     elasticsearch: deadpool_elasticsearch::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("elasticsearch.urls")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URLs of the nodes, e.g. `http://localhost:9200`.
+    pub urls: Vec<String>,
+
+    /// Username used for basic authentication.
+    pub username: Option<String>,
+
+    /// Password used for basic authentication.
+    pub password: Option<String>,
+
+    /// Base64 encoded API key used for authentication.
+    pub api_key: Option<String>,
+
+    /// PEM file containing an additional CA certificate used to verify the
+    /// nodes.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    pub ca_file: Option<std::path::PathBuf>,
+
+    /// Accept any certificate presented by the nodes.
+    ///
+    /// **Important:** This makes the connection vulnerable to
+    /// man-in-the-middle attacks and should only be used for testing.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accept_invalid_certs: bool,
+
+    /// Timeout for a whole request including reading the response body.
+    pub timeout: Option<Duration>,
+
+    /// Time a node is considered dead after a failed request. See
+    /// [`NodeHealth`](crate::NodeHealth).
+    ///
+    /// Defaults to 60 seconds.
+    pub dead_timeout: Option<Duration>,
+
+    /// [`Pool`] configuration.
+    ///
+    /// When creating a [`Cluster`] this configuration is used for every
+    /// node.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given node URLs.
+    #[must_use]
+    pub fn from_urls<I, S>(urls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            urls: urls.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::NoUrls`] or [`ConfigError::MultipleUrls`]
+    /// unless exactly one URL is configured. Use
+    /// [`Config::create_cluster()`] for multiple nodes.
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        match self.urls.as_slice() {
+            [] => Err(ConfigError::NoUrls),
+            [url] => self.node_builder(url),
+            _ => Err(ConfigError::MultipleUrls),
+        }
+    }
+
+    /// Creates a new [`Cluster`] with one [`Pool`] per configured node.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_cluster(&self, runtime: Option<Runtime>) -> Result<Cluster, CreatePoolError> {
+        if self.urls.is_empty() {
+            return Err(CreatePoolError::Config(ConfigError::NoUrls));
+        }
+        let pools = self
+            .urls
+            .iter()
+            .map(|url| {
+                let mut builder = self.node_builder(url).map_err(CreatePoolError::Config)?;
+                if let Some(runtime) = runtime {
+                    builder = builder.runtime(runtime);
+                }
+                builder.build().map_err(CreatePoolError::Build)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Cluster::new(pools))
+    }
+
+    fn node_builder(&self, url: &str) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(url, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    pub(crate) fn get_credentials(&self) -> Result<Option<Credentials>, ConfigError> {
+        match (&self.username, &self.password, &self.api_key) {
+            (None, None, None) => Ok(None),
+            (Some(username), Some(password), None) => Ok(Some(Credentials::Basic {
+                username: username.clone(),
+                password: password.clone(),
+            })),
+            (None, None, Some(api_key)) => Ok(Some(Credentials::ApiKey(api_key.clone()))),
+            (_, _, Some(_)) => Err(ConfigError::MultipleAuthMethods),
+            _ => Err(ConfigError::IncompleteCredentials),
+        }
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    pub(crate) fn get_ca_cert(&self) -> Result<Option<reqwest::Certificate>, ConfigError> {
+        let Some(ca_file) = &self.ca_file else {
+            return Ok(None);
+        };
+        let pem = std::fs::read(ca_file).map_err(|e| ConfigError::Tls(e.into()))?;
+        let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| ConfigError::Tls(e.into()))?;
+        Ok(Some(cert))
+    }
+
+    /// Returns the configured [`Config::dead_timeout`] or its default.
+    #[must_use]
+    pub fn get_dead_timeout(&self) -> Duration {
+        self.dead_timeout.unwrap_or(DEFAULT_DEAD_TIMEOUT)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the
+/// Elasticsearch configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No URL was configured.
+    NoUrls,
+
+    /// More than one URL was configured when creating a single [`Pool`].
+    MultipleUrls,
+
+    /// One of the [`Config::urls`] could not be parsed.
+    InvalidUrl(url::ParseError),
+
+    /// Only one of [`Config::username`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// Both basic authentication and an API key were configured.
+    MultipleAuthMethods,
+
+    /// The TLS configuration is invalid.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoUrls => write!(f, "No URL configured"),
+            Self::MultipleUrls => write!(
+                f,
+                "Multiple URLs configured. Use `Config::create_cluster()` instead."
+            ),
+            Self::InvalidUrl(e) => write!(f, "Invalid URL: {e}"),
+            Self::IncompleteCredentials => {
+                write!(f, "Username and password must be configured together")
+            }
+            Self::MultipleAuthMethods => {
+                write!(f, "Either basic authentication or an API key can be used")
+            }
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUrl(e) => Some(e),
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
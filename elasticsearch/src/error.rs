@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Building the [`reqwest::Client`] failed.
+    Reqwest(reqwest::Error),
+
+    /// The node is marked as dead by its [`NodeHealth`](crate::NodeHealth)
+    /// so no [`Transport`](crate::Transport) is created for it until its
+    /// dead timeout expires.
+    NodeDead,
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Reqwest(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reqwest(e) => write!(f, "Reqwest error: {e}"),
+            Self::NodeDead => write!(f, "Node is marked as dead"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Reqwest(e) => Some(e),
+            Self::NodeDead => None,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use reqwest::{Response, StatusCode};
+
+/// Maximum exponent used for the backoff of nodes failing repeatedly.
+const MAX_BACKOFF_EXPONENT: u32 = 5;
+
+/// Health of a single node as observed from the responses of its
+/// transports.
+///
+/// A node is marked as dead if a request fails to connect, times out or is
+/// answered with `502 Bad Gateway`, `503 Service Unavailable` or
+/// `504 Gateway Timeout`. It is considered alive again after the configured
+/// dead timeout, which doubles with every consecutive failure (up to 32
+/// times the configured value). Any other response marks the node as alive.
+#[derive(Debug)]
+pub struct NodeHealth {
+    dead_timeout: Duration,
+    failures: AtomicU32,
+    dead_until: Mutex<Option<Instant>>,
+}
+
+impl NodeHealth {
+    pub(crate) fn new(dead_timeout: Duration) -> Self {
+        Self {
+            dead_timeout,
+            failures: AtomicU32::new(0),
+            dead_until: Mutex::new(None),
+        }
+    }
+
+    /// Returns `true` unless the node was marked as dead and its dead
+    /// timeout did not expire yet.
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        match *self.dead_until.lock().unwrap() {
+            Some(dead_until) => dead_until <= Instant::now(),
+            None => true,
+        }
+    }
+
+    /// Returns the number of consecutive failed requests.
+    #[must_use]
+    pub fn failures(&self) -> u32 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record(&self, result: &reqwest::Result<Response>) {
+        let failed = match result {
+            Ok(response) => matches!(
+                response.status(),
+                StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Err(e) => e.is_connect() || e.is_timeout(),
+        };
+        if failed {
+            self.record_failure();
+        } else {
+            self.record_success();
+        }
+    }
+
+    fn record_success(&self) {
+        self.failures.store(0, Ordering::Relaxed);
+        *self.dead_until.lock().unwrap() = None;
+    }
+
+    fn record_failure(&self) {
+        let failures = self.failures.fetch_add(1, Ordering::Relaxed);
+        let backoff = 1 << failures.min(MAX_BACKOFF_EXPONENT);
+        *self.dead_until.lock().unwrap() = Some(Instant::now() + self.dead_timeout * backoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dead_for(health: &NodeHealth) -> Duration {
+        health
+            .dead_until
+            .lock()
+            .unwrap()
+            .expect("node is not dead")
+            .saturating_duration_since(Instant::now())
+    }
+
+    #[test]
+    fn new_node_is_alive() {
+        let health = NodeHealth::new(Duration::from_secs(60));
+        assert!(health.is_alive());
+        assert_eq!(health.failures(), 0);
+    }
+
+    #[test]
+    fn failure_marks_node_as_dead() {
+        let health = NodeHealth::new(Duration::from_secs(60));
+        health.record_failure();
+        assert!(!health.is_alive());
+        assert_eq!(health.failures(), 1);
+        assert!(dead_for(&health) <= Duration::from_secs(60));
+        assert!(dead_for(&health) > Duration::from_secs(59));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_maximum() {
+        let dead_timeout = Duration::from_secs(60);
+        let health = NodeHealth::new(dead_timeout);
+        for backoff in [1, 2, 4, 8, 16, 32, 32, 32] {
+            health.record_failure();
+            let expected = dead_timeout * backoff;
+            assert!(dead_for(&health) <= expected);
+            assert!(dead_for(&health) > expected - Duration::from_secs(1));
+        }
+        assert_eq!(health.failures(), 8);
+    }
+
+    #[test]
+    fn success_resets_node() {
+        let health = NodeHealth::new(Duration::from_secs(60));
+        health.record_failure();
+        health.record_failure();
+        health.record_success();
+        assert!(health.is_alive());
+        assert_eq!(health.failures(), 0);
+        health.record_failure();
+        assert!(dead_for(&health) <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn node_is_alive_after_dead_timeout() {
+        let health = NodeHealth::new(Duration::ZERO);
+        health.record_failure();
+        assert!(health.is_alive());
+        assert_eq!(health.failures(), 1);
+    }
+}
@@ -0,0 +1,216 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod cluster;
+mod config;
+mod error;
+mod health;
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use reqwest::{header, Body, Method, RequestBuilder, Response, Url};
+
+pub use reqwest;
+
+pub use self::{
+    cluster::Cluster,
+    config::{Config, ConfigError},
+    error::Error,
+    health::NodeHealth,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "reqwest",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Credentials sent with every request.
+#[derive(Clone)]
+enum Credentials {
+    Basic { username: String, password: String },
+    ApiKey(String),
+}
+
+// Implemented manually to not leak the password or API key.
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .finish_non_exhaustive(),
+            Self::ApiKey(_) => f.write_str("ApiKey(..)"),
+        }
+    }
+}
+
+/// Node shared by the [`Manager`] and all of its [`Transport`]s.
+#[derive(Debug)]
+struct Node {
+    url: Url,
+    credentials: Option<Credentials>,
+    health: NodeHealth,
+}
+
+/// [`Manager`] for creating and recycling [`Transport`]s to a single node.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    node: Arc<Node>,
+    timeout: Option<Duration>,
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    ca_cert: Option<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    accept_invalid_certs: bool,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the node at the given `url` using the
+    /// given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(url: &str, config: &Config) -> Result<Self, ConfigError> {
+        let url = Url::parse(url).map_err(ConfigError::InvalidUrl)?;
+        Ok(Self {
+            node: Arc::new(Node {
+                url,
+                credentials: config.get_credentials()?,
+                health: NodeHealth::new(config.get_dead_timeout()),
+            }),
+            timeout: config.timeout,
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            ca_cert: config.get_ca_cert()?,
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            accept_invalid_certs: config.accept_invalid_certs,
+        })
+    }
+
+    /// Returns the URL of the node.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.node.url
+    }
+
+    /// Returns the [`NodeHealth`] of the node.
+    #[must_use]
+    pub fn health(&self) -> &NodeHealth {
+        &self.node.health
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Transport;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Transport, Error> {
+        // Otherwise the pool would replace the transports discarded by
+        // `recycle()` with new ones to the same dead node.
+        if !self.node.health.is_alive() {
+            return Err(Error::NodeDead);
+        }
+        // Every transport keeps at most one idle connection so the size of
+        // the pool bounds the number of connections to the node.
+        let mut builder = reqwest::Client::builder().pool_max_idle_per_host(1);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        #[cfg(any(feature = "rustls", feature = "native-tls"))]
+        {
+            if let Some(ca_cert) = &self.ca_cert {
+                builder = builder.add_root_certificate(ca_cert.clone());
+            }
+            builder = builder.danger_accept_invalid_certs(self.accept_invalid_certs);
+        }
+        Ok(Transport {
+            client: builder.build()?,
+            node: self.node.clone(),
+        })
+    }
+
+    async fn recycle(&self, _: &mut Transport, _: &Metrics) -> RecycleResult {
+        if self.node.health.is_alive() {
+            Ok(())
+        } else {
+            Err(RecycleError::Backend(Error::NodeDead))
+        }
+    }
+}
+
+/// HTTP transport to a single Elasticsearch or OpenSearch node.
+#[derive(Debug)]
+pub struct Transport {
+    client: reqwest::Client,
+    node: Arc<Node>,
+}
+
+impl Transport {
+    /// Returns the URL of the node.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.node.url
+    }
+
+    /// Returns the [`NodeHealth`] of the node.
+    #[must_use]
+    pub fn health(&self) -> &NodeHealth {
+        &self.node.health
+    }
+
+    /// Creates a [`RequestBuilder`] for the given `path` of the node with
+    /// the configured credentials.
+    ///
+    /// Use [`Transport::send()`] to send the request so the response is
+    /// taken into account for the [`NodeHealth`].
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = format!(
+            "{}/{}",
+            self.node.url.as_str().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        );
+        let builder = self.client.request(method, url);
+        match &self.node.credentials {
+            Some(Credentials::Basic { username, password }) => {
+                builder.basic_auth(username, Some(password))
+            }
+            Some(Credentials::ApiKey(api_key)) => {
+                builder.header(header::AUTHORIZATION, format!("ApiKey {api_key}"))
+            }
+            None => builder,
+        }
+    }
+
+    /// Sends the given request and updates the [`NodeHealth`] based on the
+    /// outcome.
+    ///
+    /// # Errors
+    ///
+    /// See [`reqwest::Error`] for details.
+    pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let result = request.send().await;
+        self.node.health.record(&result);
+        result
+    }
+
+    /// Sends a newline delimited JSON body to the `_bulk` API.
+    ///
+    /// # Errors
+    ///
+    /// See [`reqwest::Error`] for details.
+    pub async fn bulk<B: Into<Body>>(&self, body: B) -> reqwest::Result<Response> {
+        let request = self
+            .request(Method::POST, "_bulk")
+            .header(header::CONTENT_TYPE, "application/x-ndjson")
+            .body(body);
+        self.send(request).await
+    }
+}
@@ -0,0 +1,315 @@
+use std::{fmt, time::Duration};
+
+use etcd_client::ConnectOptions;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// ETCD__ENDPOINTS=http://etcd1:2379,http://etcd2:2379,http://etcd3:2379
+/// ETCD__USER=deadpool
+/// ETCD__PASSWORD=topsecret
+/// ETCD__LEASE_TTL=10
+/// ETCD__POOL__MAX_SIZE=4
+/// ETCD__POOL__TIMEOUTS__WAIT__SECS=5
+/// ETCD__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     etcd: deadpool_etcd::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("etcd.endpoints")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Endpoints of the cluster members, e.g. `http://localhost:2379`.
+    ///
+    /// Requests are balanced over all endpoints, so a client keeps working
+    /// as long as one of the members is reachable.
+    pub endpoints: Vec<String>,
+
+    /// User used for authentication.
+    pub user: Option<String>,
+
+    /// Password used for authentication.
+    pub password: Option<String>,
+
+    /// Timeout for connecting to an endpoint.
+    pub connect_timeout: Option<Duration>,
+
+    /// Timeout applied to every request.
+    pub timeout: Option<Duration>,
+
+    /// Interval of HTTP/2 keep-alive pings.
+    pub keep_alive_interval: Option<Duration>,
+
+    /// Timeout for the answer to an HTTP/2 keep-alive ping. Only used if
+    /// [`Config::keep_alive_interval`] is set. Defaults to 20 seconds.
+    pub keep_alive_timeout: Option<Duration>,
+
+    /// Send HTTP/2 keep-alive pings while there are no active requests.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keep_alive_while_idle: bool,
+
+    /// Fail requests if the cluster has no leader instead of waiting for a
+    /// new one to be elected.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub require_leader: bool,
+
+    /// TTL in seconds of the lease granted to every [`Connection`].
+    ///
+    /// The lease is kept alive while the connection exists and revoked when
+    /// it is dropped. See [`Connection::lease_id()`].
+    ///
+    /// [`Connection`]: crate::Connection
+    /// [`Connection::lease_id()`]: crate::Connection::lease_id
+    pub lease_ttl: Option<i64>,
+
+    /// TLS configuration used for `https` endpoints.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Manager`] configuration.
+    pub manager: Option<ManagerConfig>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] with the given endpoints.
+    #[must_use]
+    pub fn from_endpoints<I, T>(endpoints: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            endpoints: endpoints.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns [`ConnectOptions`] which can be used to connect to the
+    /// cluster.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn get_connect_options(&self) -> Result<ConnectOptions, ConfigError> {
+        let mut options = ConnectOptions::new()
+            .with_keep_alive_while_idle(self.keep_alive_while_idle)
+            .with_require_leader(self.require_leader);
+        match (&self.user, &self.password) {
+            (Some(user), Some(password)) => options = options.with_user(user, password),
+            (None, None) => {}
+            _ => return Err(ConfigError::IncompleteCredentials),
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            options = options.with_connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            options = options.with_timeout(timeout);
+        }
+        if let Some(interval) = self.keep_alive_interval {
+            let timeout = self.keep_alive_timeout.unwrap_or(Duration::from_secs(20));
+            options = options.with_keep_alive(interval, timeout);
+        }
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            options = options.with_tls(tls.get_tls_options()?);
+        }
+        Ok(options)
+    }
+
+    /// Returns [`ManagerConfig`] which can be used to construct a
+    /// [`Manager`] instance.
+    #[must_use]
+    pub fn get_manager_config(&self) -> ManagerConfig {
+        self.manager.unwrap_or_default()
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// TLS configuration.
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the members.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<std::path::PathBuf>,
+
+    /// PEM file containing the client certificate used for mutual TLS.
+    pub cert_file: Option<std::path::PathBuf>,
+
+    /// PEM file containing the private key of the
+    /// [`TlsConfig::cert_file`].
+    pub key_file: Option<std::path::PathBuf>,
+
+    /// Name used for verifying the member certificates.
+    ///
+    /// Defaults to the host of the endpoint.
+    pub domain_name: Option<String>,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    fn get_tls_options(&self) -> Result<etcd_client::TlsOptions, ConfigError> {
+        use etcd_client::{Certificate, Identity, TlsOptions};
+
+        let mut tls = TlsOptions::new();
+        tls = match &self.ca_file {
+            Some(ca_file) => {
+                let pem = std::fs::read(ca_file).map_err(ConfigError::TlsFile)?;
+                tls.ca_certificate(Certificate::from_pem(pem))
+            }
+            None => tls.with_webpki_roots(),
+        };
+        match (&self.cert_file, &self.key_file) {
+            (Some(cert_file), Some(key_file)) => {
+                let cert = std::fs::read(cert_file).map_err(ConfigError::TlsFile)?;
+                let key = std::fs::read(key_file).map_err(ConfigError::TlsFile)?;
+                tls = tls.identity(Identity::from_pem(cert, key));
+            }
+            (None, None) => {}
+            _ => return Err(ConfigError::IncompleteClientCertificate),
+        }
+        if let Some(domain_name) = &self.domain_name {
+            tls = tls.domain_name(domain_name);
+        }
+        Ok(tls)
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Fast`] which does not send any request to the cluster.
+///
+/// [`Fast`]: RecyclingMethod::Fast
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Only check that the lease of the connection wasn't lost.
+    #[default]
+    Fast,
+
+    /// Additionally request the status of a member to verify the cluster is
+    /// reachable.
+    Verified,
+}
+
+/// This error is returned if there is something wrong with the etcd
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `TlsFile` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::endpoints`] were specified.
+    NoEndpoints,
+
+    /// Only one of [`Config::user`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// Only one of [`TlsConfig::cert_file`] and [`TlsConfig::key_file`] was
+    /// set.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    IncompleteClientCertificate,
+
+    /// A file of the [`TlsConfig`] could not be read.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    TlsFile(std::io::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoEndpoints => write!(f, "No endpoints specified"),
+            Self::IncompleteCredentials => {
+                write!(f, "User and password must be configured together")
+            }
+            #[cfg(feature = "tls")]
+            Self::IncompleteClientCertificate => {
+                write!(f, "Client certificate and key must be configured together")
+            }
+            #[cfg(feature = "tls")]
+            Self::TlsFile(e) => write!(f, "Failed to read TLS file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "tls")]
+            Self::TlsFile(e) => Some(e),
+            _ => None,
+        }
+    }
+}
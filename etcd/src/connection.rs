@@ -0,0 +1,128 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use etcd_client::{Client, Error};
+use tokio::{runtime::Handle, task::JoinHandle};
+
+/// Wrapper around an [`etcd_client::Client`] and the lease it holds.
+pub struct Connection {
+    client: Client,
+    lease: Option<Lease>,
+}
+
+impl Connection {
+    pub(crate) fn new(client: Client, lease: Option<Lease>) -> Self {
+        Self { client, lease }
+    }
+
+    /// Returns the ID of the lease held by this [`Connection`].
+    ///
+    /// Attach keys, locks and election proposals to this lease to have them
+    /// removed once the connection is dropped or its lease is lost.
+    #[must_use]
+    pub fn lease_id(&self) -> Option<i64> {
+        self.lease.as_ref().map(|lease| lease.id)
+    }
+
+    /// Returns `true` if the lease of this [`Connection`] could not be kept
+    /// alive and must be considered expired.
+    #[must_use]
+    pub fn lease_lost(&self) -> bool {
+        self.lease
+            .as_ref()
+            .is_some_and(|lease| !lease.alive.load(Ordering::Acquire))
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        if let Some(lease) = &self.lease {
+            // Revoke the lease right away instead of waiting for its TTL to
+            // elapse. This is only possible inside of a runtime.
+            if let Ok(handle) = Handle::try_current() {
+                let mut client = self.client.clone();
+                let id = lease.id;
+                drop(handle.spawn(async move {
+                    let _ = client.lease_revoke(id).await;
+                }));
+            }
+        }
+    }
+}
+
+// Implemented manually as `Client` doesn't implement `Debug`.
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("lease_id", &self.lease_id())
+            .field("lease_lost", &self.lease_lost())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Connection {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+}
+
+/// Lease kept alive by a background task until it is dropped.
+pub(crate) struct Lease {
+    id: i64,
+    alive: Arc<AtomicBool>,
+    task: JoinHandle<()>,
+}
+
+impl Lease {
+    /// Grants a new lease with the given `ttl` in seconds and starts
+    /// keeping it alive.
+    pub(crate) async fn grant(client: &mut Client, ttl: i64) -> Result<Self, Error> {
+        let lease = client.lease_grant(ttl, None).await?;
+        let (mut keeper, mut stream) = client.lease_keep_alive(lease.id()).await?;
+        // The server may grant a longer TTL than requested.
+        let interval = Duration::from_secs(u64::try_from(lease.ttl() / 3).unwrap_or(0).max(1));
+        let alive = Arc::new(AtomicBool::new(true));
+        let task = tokio::spawn({
+            let alive = alive.clone();
+            async move {
+                loop {
+                    tokio::time::sleep(interval).await;
+                    if keeper.keep_alive().await.is_err() {
+                        break;
+                    }
+                    match stream.message().await {
+                        Ok(Some(response)) if response.ttl() > 0 => {}
+                        _ => break,
+                    }
+                }
+                alive.store(false, Ordering::Release);
+            }
+        });
+        Ok(Self {
+            id: lease.id(),
+            alive,
+            task,
+        })
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
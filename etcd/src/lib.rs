@@ -0,0 +1,98 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod connection;
+
+use std::fmt;
+
+use deadpool::managed::{self, RecycleError};
+use etcd_client::{Client, ConnectOptions, Error};
+
+pub use etcd_client;
+
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub use self::config::TlsConfig;
+pub use self::{
+    config::{Config, ConfigError, ManagerConfig, RecyclingMethod},
+    connection::Connection,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "etcd_client",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling [`etcd_client::Client`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    endpoints: Vec<String>,
+    options: ConnectOptions,
+    lease_ttl: Option<i64>,
+    config: ManagerConfig,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        if config.endpoints.is_empty() {
+            return Err(ConfigError::NoEndpoints);
+        }
+        Ok(Self {
+            endpoints: config.endpoints.clone(),
+            options: config.get_connect_options()?,
+            lease_ttl: config.lease_ttl,
+            config: config.get_manager_config(),
+        })
+    }
+}
+
+// Implemented manually as the `ConnectOptions` contain the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("endpoints", &self.endpoints)
+            .field("lease_ttl", &self.lease_ttl)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        let mut client = Client::connect(&self.endpoints, Some(self.options.clone())).await?;
+        let lease = match self.lease_ttl {
+            Some(ttl) => Some(connection::Lease::grant(&mut client, ttl).await?),
+            None => None,
+        };
+        Ok(Connection::new(client, lease))
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        if conn.lease_lost() {
+            return Err(RecycleError::message("Lease lost"));
+        }
+        match self.config.recycling_method {
+            RecyclingMethod::Fast => {}
+            RecyclingMethod::Verified => {
+                let _ = conn.status().await?;
+            }
+        }
+        Ok(())
+    }
+}
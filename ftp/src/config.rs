@@ -0,0 +1,185 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    CreatePoolError, KeyedPool, Manager, Pool, PoolBuilder, PoolConfig, Runtime, Target, TlsConfig,
+    DEFAULT_PORT,
+};
+
+/// User name used if no [`Config::user`] is configured.
+const ANONYMOUS: &str = "anonymous";
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// FTP__HOST=files.example.com
+/// FTP__USER=exchange
+/// FTP__PASSWORD=topsecret
+/// FTP__CONNECT_TIMEOUT__SECS=10
+/// FTP__CONNECT_TIMEOUT__NANOS=0
+/// FTP__POOL__MAX_SIZE=4
+/// FTP__POOL__TIMEOUTS__WAIT__SECS=5
+/// FTP__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     ftp: deadpool_ftp::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Host name or IP address of the server.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] takes the
+    /// host from the [`Target`] instead.
+    pub host: Option<String>,
+
+    /// Port of the server. Defaults to [`DEFAULT_PORT`].
+    pub port: Option<u16>,
+
+    /// User to log in as. Defaults to `anonymous`.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] takes the
+    /// user from the [`Target`] instead.
+    pub user: Option<String>,
+
+    /// Password used to log in. Defaults to an empty password.
+    pub password: Option<String>,
+
+    /// Timeout for establishing the connection including the TLS handshake
+    /// and the login.
+    pub connect_timeout: Option<Duration>,
+
+    /// Use passive mode with the `EPSV` command instead of `PASV`, which is
+    /// required by some servers behind NAT and for IPv6.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extended_passive: bool,
+
+    /// Secures the connections using explicit FTPS (`AUTH TLS`) if set.
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration used for every [`Target`].
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `host` and `user`.
+    #[must_use]
+    pub fn new<H: Into<String>, U: Into<String>>(host: H, user: U) -> Self {
+        Self {
+            host: Some(host.into()),
+            user: Some(user.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] for [`Config::get_target()`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let target = self.get_target().map_err(CreatePoolError::Config)?;
+        self.create_target_pool(target, runtime)
+    }
+
+    /// Creates a new [`PoolBuilder`] for [`Config::get_target()`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        self.target_builder(self.get_target()?)
+    }
+
+    /// Creates a new [`KeyedPool`] which creates one [`Pool`] per
+    /// [`Target`] using this [`Config`].
+    #[must_use]
+    pub fn create_keyed_pool(&self, runtime: Option<Runtime>) -> KeyedPool {
+        KeyedPool::new(self.clone(), runtime)
+    }
+
+    pub(crate) fn create_target_pool(
+        &self,
+        target: Target,
+        runtime: Option<Runtime>,
+    ) -> Result<Pool, CreatePoolError> {
+        let mut builder = self
+            .target_builder(target)
+            .map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    fn target_builder(&self, target: Target) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(target, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the [`Target`] configured by [`Config::host`],
+    /// [`Config::port`] and [`Config::user`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::MissingHost`] if the host is not set.
+    pub fn get_target(&self) -> Result<Target, ConfigError> {
+        let host = self.host.as_deref().ok_or(ConfigError::MissingHost)?;
+        let user = self.user.as_deref().unwrap_or(ANONYMOUS);
+        Ok(Target::new(host, self.port.unwrap_or(DEFAULT_PORT), user))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the FTP
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::host`] was specified.
+    MissingHost,
+
+    /// The TLS configuration is invalid.
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHost => write!(f, "No host specified"),
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod keyed;
+mod tls;
+
+use std::{
+    fmt, io,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use deadpool::managed;
+use suppaftp::{FtpError, Mode};
+use tokio::{net::TcpStream, time::timeout};
+
+pub use suppaftp;
+
+pub use self::{
+    config::{Config, ConfigError},
+    keyed::{KeyedPool, KeyedPoolError},
+    tls::TlsConfig,
+};
+
+pub use deadpool_keyed::Target;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "suppaftp",
+    Manager,
+    managed::Object<Manager>,
+    FtpError,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<FtpError>;
+
+/// Default port of FTP servers.
+pub const DEFAULT_PORT: u16 = 21;
+
+/// FTP stream of the [`Connection`]s.
+///
+/// This stream type supports both plain and secured connections, see
+/// [`Config::tls`].
+pub type FtpStream = suppaftp::tokio::AsyncRustlsFtpStream;
+
+/// [`Manager`] for creating and recycling FTP [`Connection`]s to a single
+/// [`Target`].
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    target: Target,
+    password: String,
+    connect_timeout: Option<Duration>,
+    mode: Mode,
+    tls: Option<tokio_rustls::TlsConnector>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the given [`Target`] using the given
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(target: Target, config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self {
+            target,
+            password: config.password.clone().unwrap_or_default(),
+            connect_timeout: config.connect_timeout,
+            mode: if config.extended_passive {
+                Mode::ExtendedPassive
+            } else {
+                Mode::Passive
+            },
+            tls: config.tls.as_ref().map(TlsConfig::connector).transpose()?,
+        })
+    }
+
+    /// Returns the [`Target`] of this [`Manager`].
+    #[must_use]
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    async fn connect(&self) -> Result<Connection, FtpError> {
+        let stream = TcpStream::connect((self.target.host(), self.target.port()))
+            .await
+            .map_err(FtpError::ConnectionError)?;
+        let stream = FtpStream::connect_with_stream(stream).await?;
+        let mut stream = match &self.tls {
+            Some(connector) => {
+                let connector = suppaftp::tokio::AsyncRustlsConnector::from(connector.clone());
+                stream.into_secure(connector, self.target.host()).await?
+            }
+            None => stream,
+        };
+        stream
+            .login(self.target.user(), self.password.as_str())
+            .await?;
+        stream.set_mode(self.mode);
+        let home = stream.pwd().await?;
+        Ok(Connection {
+            stream,
+            target: self.target.clone(),
+            home,
+        })
+    }
+}
+
+// Implemented manually to not leak the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("target", &self.target)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = FtpError;
+
+    async fn create(&self) -> Result<Connection, FtpError> {
+        match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, self.connect())
+                .await
+                .map_err(|_| FtpError::ConnectionError(io::ErrorKind::TimedOut.into()))?,
+            None => self.connect().await,
+        }
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        conn.stream.noop().await?;
+        // The previous user might have changed the working directory.
+        conn.stream.cwd(&conn.home).await?;
+        Ok(())
+    }
+}
+
+/// Logged in FTP connection to a single [`Target`].
+pub struct Connection {
+    stream: FtpStream,
+    target: Target,
+    home: String,
+}
+
+impl Connection {
+    /// Returns the [`Target`] of this [`Connection`].
+    #[must_use]
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Returns the working directory right after the login.
+    ///
+    /// The working directory is changed back to it before the
+    /// [`Connection`] is reused.
+    #[must_use]
+    pub fn home(&self) -> &str {
+        &self.home
+    }
+}
+
+// Implemented manually as `ImplAsyncFtpStream` doesn't implement `Debug`.
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("target", &self.target)
+            .field("home", &self.home)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Connection {
+    type Target = FtpStream;
+
+    fn deref(&self) -> &FtpStream {
+        &self.stream
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut FtpStream {
+        &mut self.stream
+    }
+}
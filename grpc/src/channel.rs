@@ -0,0 +1,144 @@
+use std::{
+    error::Error as StdError,
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use http::{Request, Response};
+use tonic::{body::Body, transport};
+use tower_service::Service;
+
+/// Wrapper around [`tonic::transport::Channel`] which remembers whether a
+/// request failed due to an error of its connection.
+///
+/// It implements the same [`Service`] as the wrapped channel and can be
+/// passed to generated gRPC clients. Clones share the same connection and
+/// the same error state.
+///
+/// gRPC errors returned by the server (e.g. `NOT_FOUND`), requests which
+/// exceeded their timeout and streams reset by the server don't affect the
+/// channel.
+#[derive(Clone, Debug)]
+pub struct Channel {
+    inner: transport::Channel,
+    failed: Arc<AtomicBool>,
+}
+
+impl Channel {
+    pub(crate) fn new(inner: transport::Channel) -> Self {
+        Self {
+            inner,
+            failed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns `true` if a request of this channel failed due to an error
+    /// of its connection, e.g. because the server sent a `GOAWAY` frame or
+    /// the connection was reset.
+    #[must_use]
+    pub fn has_failed(&self) -> bool {
+        self.failed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the wrapped [`tonic::transport::Channel`].
+    ///
+    /// Connection errors of requests sent via the returned channel are not
+    /// detected by the pool.
+    #[must_use]
+    pub fn inner(&self) -> &transport::Channel {
+        &self.inner
+    }
+}
+
+impl Service<Request<Body>> for Channel {
+    type Response = Response<Body>;
+    type Error = transport::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<Body>, transport::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), transport::Error>> {
+        let poll = self.inner.poll_ready(cx);
+        if let Poll::Ready(Err(e)) = &poll {
+            if is_connection_error(e) {
+                self.failed.store(true, Ordering::Relaxed);
+            }
+        }
+        poll
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let response = self.inner.call(request);
+        let failed = self.failed.clone();
+        Box::pin(async move {
+            let result = response.await;
+            if let Err(e) = &result {
+                if is_connection_error(e) {
+                    failed.store(true, Ordering::Relaxed);
+                }
+            }
+            result
+        })
+    }
+}
+
+/// Returns `true` if the given error of a request is caused by its
+/// connection instead of the request itself.
+///
+/// The connection is still usable after per-request errors like an
+/// exceeded timeout (`TimeoutExpired`) or a stream reset by the server
+/// (`RST_STREAM`), so these don't mark the channel as failed.
+fn is_connection_error(error: &(dyn StdError + 'static)) -> bool {
+    let mut source = Some(error);
+    while let Some(error) = source {
+        if error.is::<tonic::ConnectError>() || error.is::<io::Error>() {
+            return true;
+        }
+        if let Some(e) = error.downcast_ref::<hyper::Error>() {
+            if e.is_closed() || e.is_canceled() || e.is_incomplete_message() {
+                return true;
+            }
+        }
+        if let Some(e) = error.downcast_ref::<h2::Error>() {
+            // `GOAWAY` frames affect the whole connection, `RST_STREAM`
+            // frames only a single request.
+            return e.is_go_away() || e.is_io();
+        }
+        source = error.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::{ConnectError, TimeoutExpired};
+
+    use super::*;
+
+    #[test]
+    fn connect_errors_are_connection_errors() {
+        let error = ConnectError(io::Error::from(io::ErrorKind::ConnectionRefused).into());
+        assert!(is_connection_error(&error));
+    }
+
+    #[test]
+    fn io_errors_are_connection_errors() {
+        let error = io::Error::from(io::ErrorKind::ConnectionReset);
+        assert!(is_connection_error(&error));
+    }
+
+    #[test]
+    fn stream_errors_are_no_connection_errors() {
+        let error = h2::Error::from(h2::Reason::CANCEL);
+        assert!(!is_connection_error(&error));
+    }
+
+    #[test]
+    fn timeouts_are_no_connection_errors() {
+        assert!(!is_connection_error(&TimeoutExpired(())));
+    }
+}
@@ -0,0 +1,264 @@
+use std::{fmt, time::Duration};
+
+use tonic::transport::Endpoint;
+
+use crate::{CreatePoolError, KeyedPool, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// GRPC__URI=http://localhost:50051
+/// GRPC__CONNECT_TIMEOUT__SECS=5
+/// GRPC__CONNECT_TIMEOUT__NANOS=0
+/// GRPC__POOL__MAX_SIZE=4
+/// GRPC__POOL__TIMEOUTS__WAIT__SECS=5
+/// GRPC__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     grpc: deadpool_grpc::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URI of the target, e.g. `http://localhost:50051`.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] receives the
+    /// target with every call.
+    pub uri: Option<String>,
+
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Option<Duration>,
+
+    /// Timeout for every request.
+    pub timeout: Option<Duration>,
+
+    /// Interval of TCP keepalive probes.
+    pub tcp_keepalive: Option<Duration>,
+
+    /// Interval of HTTP/2 `PING` frames.
+    pub http2_keep_alive_interval: Option<Duration>,
+
+    /// Timeout for the acknowledgement of HTTP/2 `PING` frames.
+    pub keep_alive_timeout: Option<Duration>,
+
+    /// Send HTTP/2 `PING` frames while there are no open streams.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keep_alive_while_idle: bool,
+
+    /// Maximum number of concurrent requests per [`Channel`](crate::Channel).
+    pub concurrency_limit: Option<usize>,
+
+    /// Value of the `user-agent` header.
+    pub user_agent: Option<String>,
+
+    /// TLS configuration used for `https` targets.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration.
+    ///
+    /// When creating a [`KeyedPool`] this configuration is used for every
+    /// target.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given target `uri`.
+    #[must_use]
+    pub fn from_uri<T: Into<String>>(uri: T) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] for [`Config::uri`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let uri = self
+            .uri
+            .as_deref()
+            .ok_or(CreatePoolError::Config(ConfigError::MissingUri))?;
+        self.create_pool_for(uri, runtime)
+    }
+
+    /// Creates a new [`PoolBuilder`] for [`Config::uri`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let uri = self.uri.as_deref().ok_or(ConfigError::MissingUri)?;
+        self.builder_for(uri)
+    }
+
+    /// Creates a new [`KeyedPool`] which creates one [`Pool`] per target
+    /// using this [`Config`].
+    #[must_use]
+    pub fn create_keyed_pool(&self, runtime: Option<Runtime>) -> KeyedPool {
+        KeyedPool::new(self.clone(), runtime)
+    }
+
+    pub(crate) fn create_pool_for(
+        &self,
+        uri: &str,
+        runtime: Option<Runtime>,
+    ) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder_for(uri).map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    fn builder_for(&self, uri: &str) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(uri, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns an [`Endpoint`] for the given target `uri` which can be used
+    /// to create channels.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn get_endpoint(&self, uri: &str) -> Result<Endpoint, ConfigError> {
+        let mut endpoint = Endpoint::from_shared(uri.to_owned()).map_err(ConfigError::Endpoint)?;
+        if let Some(connect_timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            endpoint = endpoint.tcp_keepalive(Some(tcp_keepalive));
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(keep_alive_timeout) = self.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(keep_alive_timeout);
+        }
+        endpoint = endpoint.keep_alive_while_idle(self.keep_alive_while_idle);
+        if let Some(limit) = self.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            endpoint = endpoint
+                .user_agent(user_agent.as_str())
+                .map_err(ConfigError::Endpoint)?;
+        }
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            endpoint = endpoint
+                .tls_config(tls.get_client_tls_config()?)
+                .map_err(ConfigError::Endpoint)?;
+        }
+        Ok(endpoint)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// TLS configuration.
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the server.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<std::path::PathBuf>,
+
+    /// Name used for verifying the server certificate.
+    ///
+    /// Defaults to the host of the target URI.
+    pub domain_name: Option<String>,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    fn get_client_tls_config(&self) -> Result<tonic::transport::ClientTlsConfig, ConfigError> {
+        use tonic::transport::{Certificate, ClientTlsConfig};
+
+        let mut tls = ClientTlsConfig::new();
+        tls = match &self.ca_file {
+            Some(ca_file) => {
+                let pem = std::fs::read(ca_file).map_err(ConfigError::CaFile)?;
+                tls.ca_certificate(Certificate::from_pem(pem))
+            }
+            None => tls.with_webpki_roots(),
+        };
+        if let Some(domain_name) = &self.domain_name {
+            tls = tls.domain_name(domain_name);
+        }
+        Ok(tls)
+    }
+}
+
+/// This error is returned if there is something wrong with the gRPC
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Endpoint` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::uri`] was specified.
+    MissingUri,
+
+    /// The [`Endpoint`] could not be created, e.g. because the URI or the
+    /// user agent is invalid.
+    Endpoint(tonic::transport::Error),
+
+    /// The [`TlsConfig::ca_file`] could not be read.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    CaFile(std::io::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUri => write!(f, "No URI specified"),
+            Self::Endpoint(e) => write!(f, "Invalid endpoint: {e}"),
+            #[cfg(feature = "tls")]
+            Self::CaFile(e) => write!(f, "Failed to read CA file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::MissingUri => None,
+            Self::Endpoint(e) => Some(e),
+            #[cfg(feature = "tls")]
+            Self::CaFile(e) => Some(e),
+        }
+    }
+}
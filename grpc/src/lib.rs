@@ -0,0 +1,85 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod channel;
+mod config;
+mod keyed;
+
+use deadpool::managed::{self, RecycleError};
+use tonic::transport::{Endpoint, Error};
+
+pub use tonic;
+
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub use self::config::TlsConfig;
+pub use self::{
+    channel::Channel,
+    config::{Config, ConfigError},
+    keyed::{KeyedPool, KeyedPoolError},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "tonic",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling [`Channel`]s to a single target.
+///
+/// Every [`Channel`] uses its own HTTP/2 connection, so the size of the
+/// [`Pool`] bounds the number of connections to the target.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    endpoint: Endpoint,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Endpoint`].
+    #[must_use]
+    pub fn new(endpoint: Endpoint) -> Self {
+        Self { endpoint }
+    }
+
+    /// Creates a new [`Manager`] for the given target `uri` using the given
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(uri: &str, config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self::new(config.get_endpoint(uri)?))
+    }
+
+    /// Returns the [`Endpoint`] used to create new [`Channel`]s.
+    #[must_use]
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Channel;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Channel, Error> {
+        Ok(Channel::new(self.endpoint.connect().await?))
+    }
+
+    async fn recycle(&self, channel: &mut Channel, _: &Metrics) -> RecycleResult {
+        // The wrapped channel would reconnect on its own. Discarding it
+        // instead makes sure that the pool doesn't keep channels to backends
+        // which are going away.
+        if channel.has_failed() {
+            return Err(RecycleError::message("Channel failed"));
+        }
+        Ok(())
+    }
+}
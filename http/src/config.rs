@@ -0,0 +1,212 @@
+use std::{fmt, time::Duration};
+
+#[cfg(feature = "rustls")]
+use crate::TlsConfig;
+use crate::{CreatePoolError, KeyedPool, Manager, Origin, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// HTTP__HTTP2=true
+/// HTTP__IDLE_TIMEOUT__SECS=90
+/// HTTP__IDLE_TIMEOUT__NANOS=0
+/// HTTP__POOL__MAX_SIZE=8
+/// HTTP__POOL__TIMEOUTS__WAIT__SECS=5
+/// HTTP__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     http: deadpool_http::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[allow(missing_copy_implementations)] // `tls` field is not `Copy`
+pub struct Config {
+    /// Timeout for establishing the TCP connection.
+    pub connect_timeout: Option<Duration>,
+
+    /// Set `TCP_NODELAY` on new connections.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tcp_nodelay: bool,
+
+    /// Use HTTP/2.
+    ///
+    /// `https` origins offer HTTP/2 via ALPN and fall back to HTTP/1.1.
+    /// `http` origins use HTTP/2 with prior knowledge.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub http2: bool,
+
+    /// Maximum time a connection is reused after it was created.
+    pub max_lifetime: Option<Duration>,
+
+    /// Maximum time a connection stays in the pool without being used.
+    pub idle_timeout: Option<Duration>,
+
+    /// TLS configuration used for `https` origins.
+    ///
+    /// The Mozilla root certificates are used if this is not set.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration used for every [`Origin`].
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Pool`] for the given [`Origin`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(
+        &self,
+        origin: Origin,
+        runtime: Option<Runtime>,
+    ) -> Result<Pool, CreatePoolError> {
+        self.builder(origin, runtime)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] for the given [`Origin`] using this
+    /// [`Config`].
+    ///
+    /// The connections are always driven by the current tokio runtime, so
+    /// the [`Runtime`] of the [`Pool`] must be the tokio one, if any.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(
+        &self,
+        origin: Origin,
+        runtime: Option<Runtime>,
+    ) -> Result<PoolBuilder, ConfigError> {
+        let builder =
+            Pool::builder(Manager::from_config(origin, self)?).config(self.get_pool_config());
+        match runtime {
+            #[cfg(feature = "rt_tokio_1")]
+            Some(runtime @ Runtime::Tokio1) => Ok(builder.runtime(runtime)),
+            None => Ok(builder),
+            #[allow(unreachable_patterns)]
+            Some(runtime) => Err(ConfigError::UnsupportedRuntime(runtime)),
+        }
+    }
+
+    /// Creates a new [`KeyedPool`] which creates one [`Pool`] per
+    /// [`Origin`] using this [`Config`].
+    #[must_use]
+    pub fn create_keyed_pool(&self, runtime: Option<Runtime>) -> KeyedPool {
+        KeyedPool::new(self.clone(), runtime)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the HTTP
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// The URI of a request has no valid `http` or `https` [`Origin`].
+    InvalidOrigin,
+
+    /// The [`Runtime`] is not supported as the connections are driven by
+    /// tokio.
+    UnsupportedRuntime(Runtime),
+
+    /// An `https` [`Origin`] was used without enabling the `rustls`
+    /// feature.
+    #[cfg(not(feature = "rustls"))]
+    HttpsNotSupported,
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOrigin => write!(f, "URI has no valid http or https origin"),
+            Self::UnsupportedRuntime(runtime) => {
+                write!(
+                    f,
+                    "Unsupported runtime {runtime:?}, only tokio is supported"
+                )
+            }
+            #[cfg(not(feature = "rustls"))]
+            Self::HttpsNotSupported => {
+                write!(f, "https requires the `rustls` feature")
+            }
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scheme;
+
+    use super::*;
+
+    #[cfg(feature = "rt_tokio_1")]
+    #[tokio::test]
+    async fn tokio_runtime_is_supported() {
+        let origin = Origin::new(Scheme::Http, "localhost", 8080);
+        assert!(Config::default()
+            .create_pool(origin, Some(Runtime::Tokio1))
+            .is_ok());
+    }
+
+    #[cfg(not(feature = "rustls"))]
+    #[test]
+    fn https_requires_rustls() {
+        let origin = Origin::new(Scheme::Https, "localhost", 443);
+        assert!(matches!(
+            Config::default().builder(origin, None),
+            Err(ConfigError::HttpsNotSupported)
+        ));
+    }
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn https_uses_rustls() {
+        let origin = Origin::new(Scheme::Https, "localhost", 443);
+        assert!(Config::default().builder(origin, None).is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use std::{future::poll_fn, task::Poll};
+
+use bytes::Bytes;
+use http_body_util::{combinators::BoxBody, BodyExt};
+use hyper::{
+    body::Incoming,
+    client::conn::{http1, http2},
+    header::{self, HeaderValue},
+    rt::{Read, Write},
+    Request, Response, Uri, Version,
+};
+use hyper_util::rt::TokioExecutor;
+
+use crate::Origin;
+
+/// Boxed error of request bodies.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Type erased body used for all requests of a [`Connection`].
+type Body = BoxBody<Bytes, BoxError>;
+
+#[derive(Debug)]
+enum Sender {
+    Http1(http1::SendRequest<Body>),
+    Http2(http2::SendRequest<Body>),
+}
+
+/// Single HTTP/1.1 or HTTP/2 connection to an [`Origin`].
+#[derive(Debug)]
+pub struct Connection {
+    origin: Origin,
+    sender: Sender,
+}
+
+impl Connection {
+    /// Performs the HTTP handshake on the given `io` and spawns the task
+    /// driving the connection on the current tokio runtime, which the TCP
+    /// stream is bound to anyway.
+    pub(crate) async fn handshake<T>(origin: Origin, io: T, http2: bool) -> hyper::Result<Self>
+    where
+        T: Read + Write + Unpin + Send + 'static,
+    {
+        let sender = if http2 {
+            let (sender, conn) = http2::handshake(TokioExecutor::new(), io).await?;
+            drop(tokio::spawn(conn));
+            Sender::Http2(sender)
+        } else {
+            let (sender, conn) = http1::handshake(io).await?;
+            drop(tokio::spawn(conn));
+            Sender::Http1(sender)
+        };
+        Ok(Self { origin, sender })
+    }
+
+    /// Returns the [`Origin`] this connection is connected to.
+    #[must_use]
+    pub fn origin(&self) -> &Origin {
+        &self.origin
+    }
+
+    /// Returns the negotiated HTTP version.
+    #[must_use]
+    pub fn version(&self) -> Version {
+        match self.sender {
+            Sender::Http1(_) => Version::HTTP_11,
+            Sender::Http2(_) => Version::HTTP_2,
+        }
+    }
+
+    /// Returns `true` if the connection was closed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        match &self.sender {
+            Sender::Http1(sender) => sender.is_closed(),
+            Sender::Http2(sender) => sender.is_closed(),
+        }
+    }
+
+    /// Returns `true` if the connection can send another request right
+    /// away.
+    ///
+    /// An HTTP/1.1 connection is not ready while the body of the previous
+    /// response was not read completely.
+    pub(crate) async fn is_ready(&mut self) -> bool {
+        poll_fn(|cx| {
+            let poll = match &mut self.sender {
+                Sender::Http1(sender) => sender.poll_ready(cx),
+                Sender::Http2(sender) => sender.poll_ready(cx),
+            };
+            Poll::Ready(matches!(poll, Poll::Ready(Ok(()))))
+        })
+        .await
+    }
+
+    /// Sends a request using this connection.
+    ///
+    /// The URI of the request may be absolute or only contain the path. It
+    /// is rewritten as required by the HTTP version of this connection. The
+    /// `host` header is added for HTTP/1.1 if it is missing.
+    ///
+    /// # Errors
+    ///
+    /// See [`hyper::Error`] for details.
+    pub async fn send_request<B>(
+        &mut self,
+        request: Request<B>,
+    ) -> hyper::Result<Response<Incoming>>
+    where
+        B: hyper::body::Body<Data = Bytes> + Send + Sync + 'static,
+        B::Error: Into<BoxError>,
+    {
+        let (mut parts, body) = request.into_parts();
+        let path_and_query = parts
+            .uri
+            .path_and_query()
+            .map_or("/", |p| p.as_str())
+            .to_owned();
+        let body = body.map_err(Into::into).boxed();
+        match &mut self.sender {
+            Sender::Http1(sender) => {
+                parts.uri = Uri::try_from(path_and_query).unwrap_or_default();
+                parts.version = Version::HTTP_11;
+                if !parts.headers.contains_key(header::HOST) {
+                    if let Ok(host) = HeaderValue::from_str(&self.origin.authority()) {
+                        let _ = parts.headers.insert(header::HOST, host);
+                    }
+                }
+                sender.ready().await?;
+                sender.send_request(Request::from_parts(parts, body)).await
+            }
+            Sender::Http2(sender) => {
+                if let Ok(uri) = Uri::try_from(format!("{}{path_and_query}", self.origin)) {
+                    parts.uri = uri;
+                }
+                parts.version = Version::HTTP_2;
+                sender.ready().await?;
+                sender.send_request(Request::from_parts(parts, body)).await
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::{fmt, io};
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Establishing the TCP or TLS connection failed.
+    Connect(io::Error),
+
+    /// The HTTP handshake failed.
+    Http(hyper::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connect(e) => write!(f, "Failed to connect: {e}"),
+            Self::Http(e) => write!(f, "HTTP handshake failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Connect(e) => Some(e),
+            Self::Http(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use deadpool_keyed::{PoolMap, DEFAULT_IDLE_TIMEOUT};
+use hyper::{body::Incoming, Request, Response};
+
+use crate::{
+    BoxError, Config, ConfigError, CreatePoolError, Manager, Object, Origin, Pool, PoolError,
+    Runtime,
+};
+
+/// Pools of [`Connection`](crate::Connection)s keyed by their [`Origin`].
+///
+/// The [`Pool`] of an [`Origin`] is created on first use with the same
+/// [`Config`] for every origin. Pools which are idle for the idle timeout
+/// are evicted, see [`PoolMap`] for details.
+#[derive(Clone, Debug)]
+pub struct KeyedPool {
+    inner: Arc<KeyedPoolInner>,
+}
+
+#[derive(Debug)]
+struct KeyedPoolInner {
+    config: Config,
+    runtime: Option<Runtime>,
+    pools: PoolMap<Origin, Manager>,
+}
+
+impl KeyedPool {
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the [`DEFAULT_IDLE_TIMEOUT`] of 5 minutes.
+    #[must_use]
+    pub fn new(config: Config, runtime: Option<Runtime>) -> Self {
+        Self::with_idle_timeout(config, runtime, Some(DEFAULT_IDLE_TIMEOUT))
+    }
+
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the given `idle_timeout`. Pools are never evicted
+    /// if it is [`None`].
+    #[must_use]
+    pub fn with_idle_timeout(
+        config: Config,
+        runtime: Option<Runtime>,
+        idle_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(KeyedPoolInner {
+                config,
+                runtime,
+                pools: PoolMap::new(idle_timeout),
+            }),
+        }
+    }
+
+    /// Returns the [`Pool`] for the given [`Origin`] and creates it if
+    /// necessary.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn pool(&self, origin: &Origin) -> Result<Pool, CreatePoolError> {
+        self.inner.pools.get_or_try_insert_with(origin, || {
+            self.inner
+                .config
+                .create_pool(origin.clone(), self.inner.runtime)
+        })
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the given [`Origin`].
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn get(&self, origin: &Origin) -> Result<Object, KeyedPoolError> {
+        let pool = self.pool(origin).map_err(KeyedPoolError::Create)?;
+        pool.get().await.map_err(KeyedPoolError::Pool)
+    }
+
+    /// Sends the given request using a connection to the [`Origin`] of its
+    /// absolute URI.
+    ///
+    /// The connection is returned to its [`Pool`] as soon as the response
+    /// head was received. An HTTP/1.1 connection is only reused if the
+    /// response body was read completely before the connection is
+    /// retrieved again.
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn send_request<B>(
+        &self,
+        request: Request<B>,
+    ) -> Result<Response<Incoming>, KeyedPoolError>
+    where
+        B: hyper::body::Body<Data = bytes::Bytes> + Send + Sync + 'static,
+        B::Error: Into<BoxError>,
+    {
+        let origin = Origin::from_uri(request.uri()).ok_or(KeyedPoolError::Create(
+            CreatePoolError::Config(ConfigError::InvalidOrigin),
+        ))?;
+        let mut conn = self.get(&origin).await?;
+        conn.send_request(request)
+            .await
+            .map_err(KeyedPoolError::Request)
+    }
+
+    /// Removes the expired connections from all pools and the pools which
+    /// are idle. See [`Manager::is_expired()`] and
+    /// [`PoolMap::evict_idle()`].
+    ///
+    /// Connections are only checked when they are retrieved from a pool.
+    /// Call this method periodically to close idle connections early.
+    pub fn evict_expired(&self) {
+        for pool in self.inner.pools.pools() {
+            let manager = pool.manager();
+            let _ = pool.retain(|_, metrics| !manager.is_expired(&metrics));
+        }
+        self.inner.pools.evict_idle();
+    }
+
+    /// Removes the [`Pool`] of the given [`Origin`] and closes it.
+    pub fn remove(&self, origin: &Origin) {
+        if let Some(pool) = self.inner.pools.remove(origin) {
+            pool.close();
+        }
+    }
+
+    /// Returns the origins of all pools which were not evicted.
+    #[must_use]
+    pub fn origins(&self) -> Vec<Origin> {
+        self.inner.pools.keys()
+    }
+}
+
+/// Possible errors returned by a [`KeyedPool`].
+#[derive(Debug)]
+pub enum KeyedPoolError {
+    /// The [`Pool`] for the [`Origin`] could not be created.
+    Create(CreatePoolError),
+
+    /// No [`Object`] could be retrieved from the [`Pool`].
+    Pool(PoolError),
+
+    /// Sending the request failed.
+    Request(hyper::Error),
+}
+
+impl fmt::Display for KeyedPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create(e) => write!(f, "Failed to create pool: {e}"),
+            Self::Pool(e) => write!(f, "Failed to get connection: {e}"),
+            Self::Request(e) => write!(f, "Failed to send request: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for KeyedPoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Create(e) => Some(e),
+            Self::Pool(e) => Some(e),
+            Self::Request(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use bytes::Bytes;
+    use http_body_util::{BodyExt, Empty, Full};
+    use hyper::{
+        server::conn::{http1, http2},
+        service::service_fn,
+        Version,
+    };
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use tokio::net::TcpListener;
+
+    use crate::Scheme;
+
+    use super::*;
+
+    /// Serves HTTP/1.1 or HTTP/2 with prior knowledge on a local port
+    /// answering every request with its path and counts the accepted
+    /// connections.
+    async fn serve(http2: bool) -> (Origin, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let origin = Origin::new(
+            Scheme::Http,
+            "127.0.0.1",
+            listener.local_addr().unwrap().port(),
+        );
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        drop(tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let _ = counter.fetch_add(1, Ordering::Relaxed);
+                let service = service_fn(|request: Request<Incoming>| async move {
+                    let path = request.uri().path().to_owned();
+                    Ok::<_, Infallible>(Response::new(Full::new(Bytes::from(path))))
+                });
+                let io = TokioIo::new(stream);
+                if http2 {
+                    drop(tokio::spawn(
+                        http2::Builder::new(TokioExecutor::new()).serve_connection(io, service),
+                    ));
+                } else {
+                    drop(tokio::spawn(
+                        http1::Builder::new().serve_connection(io, service),
+                    ));
+                }
+            }
+        }));
+        (origin, connections)
+    }
+
+    fn request(uri: &str) -> Request<Empty<Bytes>> {
+        Request::get(uri).body(Empty::new()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn relative_uris_are_rejected() {
+        let pools = Config::default().create_keyed_pool(None);
+        assert!(matches!(
+            pools.send_request(request("/health")).await,
+            Err(KeyedPoolError::Create(CreatePoolError::Config(
+                ConfigError::InvalidOrigin
+            )))
+        ));
+        assert!(pools.origins().is_empty());
+    }
+
+    async fn assert_connection_is_reused(http2: bool) {
+        let (origin, connections) = serve(http2).await;
+        let config = Config {
+            http2,
+            ..Config::default()
+        };
+        let pools = config.create_keyed_pool(None);
+        for path in ["/a", "/b", "/c"] {
+            let response = pools
+                .send_request(request(&format!("{origin}{path}")))
+                .await
+                .unwrap();
+            let version = if http2 {
+                Version::HTTP_2
+            } else {
+                Version::HTTP_11
+            };
+            assert_eq!(response.version(), version);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            assert_eq!(body, path);
+        }
+        assert_eq!(connections.load(Ordering::Relaxed), 1);
+        assert_eq!(pools.origins(), [origin]);
+    }
+
+    #[tokio::test]
+    async fn http1_connections_are_reused() {
+        assert_connection_is_reused(false).await;
+    }
+
+    #[tokio::test]
+    async fn http2_connections_are_reused() {
+        assert_connection_is_reused(true).await;
+    }
+}
@@ -0,0 +1,164 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod connection;
+mod error;
+mod keyed;
+mod origin;
+#[cfg(feature = "rustls")]
+mod tls;
+
+use std::{fmt, io};
+
+use deadpool::managed::{self, RecycleError};
+use hyper_util::rt::TokioIo;
+use tokio::{net::TcpStream, time::timeout};
+
+pub use hyper;
+
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+pub use self::tls::TlsConfig;
+pub use self::{
+    config::{Config, ConfigError},
+    connection::{BoxError, Connection},
+    error::Error,
+    keyed::{KeyedPool, KeyedPoolError},
+    origin::{Origin, Scheme},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "hyper",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling [`Connection`]s to a single
+/// [`Origin`].
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    origin: Origin,
+    config: Config,
+    #[cfg(feature = "rustls")]
+    tls: Option<tokio_rustls::TlsConnector>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the given [`Origin`] using the given
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(origin: Origin, config: &Config) -> Result<Self, ConfigError> {
+        #[cfg(feature = "rustls")]
+        let tls = match origin.scheme() {
+            Scheme::Http => None,
+            Scheme::Https => Some(
+                config
+                    .tls
+                    .clone()
+                    .unwrap_or_default()
+                    .connector(config.http2)?,
+            ),
+        };
+        #[cfg(not(feature = "rustls"))]
+        if origin.scheme() == Scheme::Https {
+            return Err(ConfigError::HttpsNotSupported);
+        }
+        Ok(Self {
+            origin,
+            config: config.clone(),
+            #[cfg(feature = "rustls")]
+            tls,
+        })
+    }
+
+    /// Returns the [`Origin`] of this [`Manager`].
+    #[must_use]
+    pub fn origin(&self) -> &Origin {
+        &self.origin
+    }
+
+    /// Returns `true` if a [`Connection`] with the given [`Metrics`] exceeded
+    /// [`Config::max_lifetime`] or [`Config::idle_timeout`].
+    #[must_use]
+    pub fn is_expired(&self, metrics: &Metrics) -> bool {
+        self.config
+            .max_lifetime
+            .is_some_and(|max_lifetime| metrics.age() > max_lifetime)
+            || self
+                .config
+                .idle_timeout
+                .is_some_and(|idle_timeout| metrics.last_used() > idle_timeout)
+    }
+
+    async fn connect(&self) -> io::Result<TcpStream> {
+        let connect = TcpStream::connect((self.origin.host(), self.origin.port()));
+        let tcp = match self.config.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, connect)
+                .await
+                .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??,
+            None => connect.await?,
+        };
+        tcp.set_nodelay(self.config.tcp_nodelay)?;
+        Ok(tcp)
+    }
+}
+
+// Implemented manually as `TlsConnector` doesn't implement `Debug`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("origin", &self.origin)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        let tcp = self.connect().await.map_err(Error::Connect)?;
+        #[cfg(feature = "rustls")]
+        if let Some(tls) = &self.tls {
+            use tokio_rustls::rustls::pki_types::ServerName;
+
+            let server_name = ServerName::try_from(self.origin.host().to_owned())
+                .map_err(|e| Error::Connect(io::Error::new(io::ErrorKind::InvalidInput, e)))?;
+            let stream = tls
+                .connect(server_name, tcp)
+                .await
+                .map_err(Error::Connect)?;
+            let http2 = stream.get_ref().1.alpn_protocol() == Some(b"h2");
+            return Connection::handshake(self.origin.clone(), TokioIo::new(stream), http2)
+                .await
+                .map_err(Error::Http);
+        }
+        Connection::handshake(self.origin.clone(), TokioIo::new(tcp), self.config.http2)
+            .await
+            .map_err(Error::Http)
+    }
+
+    async fn recycle(&self, conn: &mut Connection, metrics: &Metrics) -> RecycleResult {
+        if self.is_expired(metrics) {
+            return Err(RecycleError::message("Connection expired"));
+        }
+        if conn.is_closed() {
+            return Err(RecycleError::message("Connection closed"));
+        }
+        if !conn.is_ready().await {
+            return Err(RecycleError::message("Connection not ready"));
+        }
+        Ok(())
+    }
+}
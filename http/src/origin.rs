@@ -0,0 +1,161 @@
+use std::fmt;
+
+use hyper::Uri;
+
+/// Scheme of an [`Origin`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Scheme {
+    /// Plain `http`.
+    Http,
+
+    /// `https` using TLS.
+    Https,
+}
+
+impl Scheme {
+    /// Returns the default port of this [`Scheme`].
+    #[must_use]
+    pub fn default_port(self) -> u16 {
+        match self {
+            Self::Http => 80,
+            Self::Https => 443,
+        }
+    }
+
+    /// Returns the name of this [`Scheme`] as used in URIs.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Https => "https",
+        }
+    }
+}
+
+/// Scheme, host and port identifying the server of a connection.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Origin {
+    scheme: Scheme,
+    host: String,
+    port: u16,
+}
+
+impl Origin {
+    /// Creates a new [`Origin`].
+    #[must_use]
+    pub fn new<T: Into<String>>(scheme: Scheme, host: T, port: u16) -> Self {
+        Self {
+            scheme,
+            host: host.into(),
+            port,
+        }
+    }
+
+    /// Returns the [`Origin`] of the given absolute `uri`.
+    ///
+    /// Returns [`None`] if the `uri` has no host or the scheme is neither
+    /// `http` nor `https`.
+    #[must_use]
+    pub fn from_uri(uri: &Uri) -> Option<Self> {
+        let scheme = match uri.scheme_str()? {
+            "http" => Scheme::Http,
+            "https" => Scheme::Https,
+            _ => return None,
+        };
+        let host = uri.host()?.trim_start_matches('[').trim_end_matches(']');
+        let port = uri.port_u16().unwrap_or(scheme.default_port());
+        Some(Self::new(scheme, host, port))
+    }
+
+    /// Returns the [`Scheme`] of this [`Origin`].
+    #[must_use]
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns the host of this [`Origin`].
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port of this [`Origin`].
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the value of the `host` header for this [`Origin`].
+    pub(crate) fn authority(&self) -> String {
+        let host = if self.host.contains(':') {
+            format!("[{}]", self.host)
+        } else {
+            self.host.clone()
+        };
+        if self.port == self.scheme.default_port() {
+            host
+        } else {
+            format!("{host}:{}", self.port)
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme.as_str(), self.authority())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn origin(uri: &str) -> Option<Origin> {
+        Origin::from_uri(&uri.parse().unwrap())
+    }
+
+    #[test]
+    fn default_ports_are_used() {
+        assert_eq!(
+            origin("http://example.com/health"),
+            Some(Origin::new(Scheme::Http, "example.com", 80))
+        );
+        assert_eq!(
+            origin("https://example.com"),
+            Some(Origin::new(Scheme::Https, "example.com", 443))
+        );
+    }
+
+    #[test]
+    fn explicit_ports_are_kept() {
+        assert_eq!(
+            origin("http://example.com:8080/health?verbose"),
+            Some(Origin::new(Scheme::Http, "example.com", 8080))
+        );
+    }
+
+    #[test]
+    fn brackets_of_ipv6_hosts_are_removed() {
+        let origin = origin("http://[::1]:8080/").unwrap();
+        assert_eq!(origin.host(), "::1");
+        assert_eq!(origin.authority(), "[::1]:8080");
+    }
+
+    #[test]
+    fn other_schemes_and_relative_uris_are_rejected() {
+        assert_eq!(origin("ftp://example.com"), None);
+        assert_eq!(origin("/health"), None);
+    }
+
+    #[test]
+    fn default_ports_are_not_displayed() {
+        assert_eq!(
+            Origin::new(Scheme::Https, "example.com", 443).to_string(),
+            "https://example.com"
+        );
+        assert_eq!(
+            Origin::new(Scheme::Http, "example.com", 443).to_string(),
+            "http://example.com:443"
+        );
+    }
+}
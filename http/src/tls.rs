@@ -0,0 +1,30 @@
+use std::{path::PathBuf, sync::Arc};
+
+use tokio_rustls::TlsConnector;
+
+use crate::ConfigError;
+
+/// TLS configuration used for `https` origins.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the servers.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    /// Creates the [`TlsConnector`] offering HTTP/2 via ALPN if `http2` is
+    /// enabled.
+    pub(crate) fn connector(&self, http2: bool) -> Result<TlsConnector, ConfigError> {
+        let mut config = deadpool_rustls::client_config(self.ca_file.as_deref())
+            .map_err(|e| ConfigError::Tls(e.into()))?;
+        config.alpn_protocols = if http2 {
+            vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+        } else {
+            vec![b"http/1.1".to_vec()]
+        };
+        Ok(TlsConnector::from(Arc::new(config)))
+    }
+}
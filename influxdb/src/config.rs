@@ -0,0 +1,243 @@
+use std::{fmt, time::Duration};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Default maximum number of points sent with a single request.
+///
+/// This is the batch size recommended by the InfluxDB documentation.
+const DEFAULT_BATCH_SIZE: usize = 5000;
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// INFLUXDB__URL=http://localhost:8086
+/// INFLUXDB__ORG=deadpool
+/// INFLUXDB__BUCKET=metrics
+/// INFLUXDB__TOKEN=topsecret
+/// INFLUXDB__PRECISION=ms
+/// INFLUXDB__POOL__MAX_SIZE=8
+/// INFLUXDB__POOL__TIMEOUTS__WAIT__SECS=5
+/// INFLUXDB__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     influxdb: deadpool_influxdb::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URL of the InfluxDB server, e.g. `http://localhost:8086`.
+    pub url: Option<String>,
+
+    /// Organization the [`Config::bucket`] belongs to.
+    pub org: Option<String>,
+
+    /// Bucket the points are written to.
+    pub bucket: Option<String>,
+
+    /// API token used for authentication.
+    pub token: Option<String>,
+
+    /// [`Precision`] of the timestamps of the written points.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub precision: Precision,
+
+    /// Maximum number of points sent with a single request by
+    /// [`WriteClient::write_points()`](crate::WriteClient::write_points).
+    ///
+    /// Defaults to 5000.
+    pub batch_size: Option<usize>,
+
+    /// Timeout for a whole request including reading the response body.
+    pub timeout: Option<Duration>,
+
+    /// PEM file containing an additional CA certificate used to verify the
+    /// server.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    pub ca_file: Option<std::path::PathBuf>,
+
+    /// Accept any certificate presented by the server.
+    ///
+    /// **Important:** This makes the connection vulnerable to
+    /// man-in-the-middle attacks and should only be used for testing.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accept_invalid_certs: bool,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for writing to the given `bucket` of the
+    /// given `org` on the server at `url`.
+    #[must_use]
+    pub fn new<U, O, B>(url: U, org: O, bucket: B) -> Self
+    where
+        U: Into<String>,
+        O: Into<String>,
+        B: Into<String>,
+    {
+        Self {
+            url: Some(url.into()),
+            org: Some(org.into()),
+            bucket: Some(bucket.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the configured [`Config::batch_size`] or its default.
+    #[must_use]
+    pub fn get_batch_size(&self) -> usize {
+        self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1)
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    pub(crate) fn get_ca_cert(&self) -> Result<Option<reqwest::Certificate>, ConfigError> {
+        let Some(ca_file) = &self.ca_file else {
+            return Ok(None);
+        };
+        let pem = std::fs::read(ca_file).map_err(|e| ConfigError::Tls(e.into()))?;
+        let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| ConfigError::Tls(e.into()))?;
+        Ok(Some(cert))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Precision of the timestamps of written points.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Precision {
+    /// Nanoseconds.
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "ns"))]
+    Nanoseconds,
+
+    /// Microseconds.
+    #[cfg_attr(feature = "serde", serde(rename = "us"))]
+    Microseconds,
+
+    /// Milliseconds.
+    #[cfg_attr(feature = "serde", serde(rename = "ms"))]
+    Milliseconds,
+
+    /// Seconds.
+    #[cfg_attr(feature = "serde", serde(rename = "s"))]
+    Seconds,
+}
+
+impl Precision {
+    /// Returns the value of the `precision` query parameter of the write
+    /// API.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Nanoseconds => "ns",
+            Self::Microseconds => "us",
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+        }
+    }
+}
+
+/// This error is returned if there is something wrong with the InfluxDB
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::url`] was specified.
+    MissingUrl,
+
+    /// The [`Config::url`] could not be parsed.
+    InvalidUrl(url::ParseError),
+
+    /// The [`Config::url`] is neither an `http` nor an `https` URL.
+    UnsupportedScheme(String),
+
+    /// No [`Config::org`] was specified.
+    MissingOrg,
+
+    /// No [`Config::bucket`] was specified.
+    MissingBucket,
+
+    /// The TLS configuration is invalid.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUrl => write!(f, "No URL specified"),
+            Self::InvalidUrl(e) => write!(f, "Invalid URL: {e}"),
+            Self::UnsupportedScheme(scheme) => {
+                write!(
+                    f,
+                    "Unsupported URL scheme `{scheme}`, expected http or https"
+                )
+            }
+            Self::MissingOrg => write!(f, "No organization specified"),
+            Self::MissingBucket => write!(f, "No bucket specified"),
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUrl(e) => Some(e),
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+
+/// Possible errors returned when writing to InfluxDB.
+#[derive(Debug)]
+pub enum WriteError {
+    /// The request could not be sent or the response could not be read.
+    Request(reqwest::Error),
+
+    /// InfluxDB rejected the write.
+    Rejected {
+        /// Status code of the response.
+        status: StatusCode,
+
+        /// Body of the response which usually describes the problem.
+        message: String,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Request(e) => write!(f, "Request failed: {e}"),
+            Self::Rejected { status, message } => {
+                write!(f, "Write rejected with status {status}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request(e) => Some(e),
+            Self::Rejected { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for WriteError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Request(e)
+    }
+}
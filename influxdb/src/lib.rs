@@ -0,0 +1,243 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+mod point;
+
+use std::{fmt::Write as _, mem, sync::Arc, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use reqwest::{header, Body, Url};
+
+pub use reqwest;
+
+pub use self::{
+    config::{Config, ConfigError, Precision},
+    error::WriteError,
+    point::{FieldValue, Point},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "reqwest",
+    Manager,
+    managed::Object<Manager>,
+    reqwest::Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<reqwest::Error>;
+
+/// Endpoints shared by the [`Manager`] and all of its [`WriteClient`]s.
+struct Server {
+    url: Url,
+    ping_url: Url,
+    write_url: Url,
+    authorization: Option<String>,
+}
+
+// Implemented manually to not leak the token.
+impl std::fmt::Debug for Server {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Server")
+            .field("url", &self.url)
+            .field("write_url", &self.write_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`Manager`] for creating and recycling [`WriteClient`]s.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    server: Arc<Server>,
+    batch_size: usize,
+    timeout: Option<Duration>,
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    ca_cert: Option<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    accept_invalid_certs: bool,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let url = config.url.as_deref().ok_or(ConfigError::MissingUrl)?;
+        let url = Url::parse(url).map_err(ConfigError::InvalidUrl)?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(ConfigError::UnsupportedScheme(url.scheme().to_owned()));
+        }
+        let org = config.org.as_deref().ok_or(ConfigError::MissingOrg)?;
+        let bucket = config.bucket.as_deref().ok_or(ConfigError::MissingBucket)?;
+        let mut write_url = endpoint(&url, &["api", "v2", "write"]);
+        let _ = write_url
+            .query_pairs_mut()
+            .append_pair("org", org)
+            .append_pair("bucket", bucket)
+            .append_pair("precision", config.precision.as_str());
+        Ok(Self {
+            server: Arc::new(Server {
+                ping_url: endpoint(&url, &["ping"]),
+                write_url,
+                authorization: config.token.as_ref().map(|token| format!("Token {token}")),
+                url,
+            }),
+            batch_size: config.get_batch_size(),
+            timeout: config.timeout,
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            ca_cert: config.get_ca_cert()?,
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            accept_invalid_certs: config.accept_invalid_certs,
+        })
+    }
+
+    /// Returns the URL of the server.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.server.url
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = WriteClient;
+    type Error = reqwest::Error;
+
+    async fn create(&self) -> Result<WriteClient, reqwest::Error> {
+        // Every client keeps at most one idle connection so the size of the
+        // pool bounds the number of connections to the server.
+        let mut builder = reqwest::Client::builder().pool_max_idle_per_host(1);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        #[cfg(any(feature = "rustls", feature = "native-tls"))]
+        {
+            if let Some(ca_cert) = &self.ca_cert {
+                builder = builder.add_root_certificate(ca_cert.clone());
+            }
+            builder = builder.danger_accept_invalid_certs(self.accept_invalid_certs);
+        }
+        Ok(WriteClient {
+            client: builder.build()?,
+            server: self.server.clone(),
+            batch_size: self.batch_size,
+        })
+    }
+
+    async fn recycle(&self, client: &mut WriteClient, _: &Metrics) -> RecycleResult {
+        client.ping().await.map_err(RecycleError::Backend)
+    }
+}
+
+/// Client writing to the configured bucket of an InfluxDB v2 server.
+#[derive(Debug)]
+pub struct WriteClient {
+    client: reqwest::Client,
+    server: Arc<Server>,
+    batch_size: usize,
+}
+
+impl WriteClient {
+    /// Returns the URL of the server.
+    #[must_use]
+    pub fn url(&self) -> &Url {
+        &self.server.url
+    }
+
+    /// Returns the underlying [`reqwest::Client`], e.g. for sending queries.
+    #[must_use]
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Checks that the server is available using the `/ping` endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`reqwest::Error`] if the request failed or the server
+    /// didn't respond with a success status.
+    pub async fn ping(&self) -> reqwest::Result<()> {
+        let _ = self
+            .client
+            .get(self.server.ping_url.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// Writes the given body of newline separated line protocol.
+    ///
+    /// # Errors
+    ///
+    /// See [`WriteError`] for details.
+    pub async fn write<B: Into<Body>>(&self, body: B) -> Result<(), WriteError> {
+        let mut request = self
+            .client
+            .post(self.server.write_url.clone())
+            .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(body);
+        if let Some(authorization) = &self.server.authorization {
+            request = request.header(header::AUTHORIZATION, authorization);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        Err(WriteError::Rejected {
+            status,
+            message: response.text().await.unwrap_or_default(),
+        })
+    }
+
+    /// Writes the given points splitting them into requests of at most
+    /// [`Config::batch_size`] points.
+    ///
+    /// The batches are written one after another. If a batch is rejected
+    /// the remaining points are not written.
+    ///
+    /// # Errors
+    ///
+    /// See [`WriteError`] for details.
+    pub async fn write_points<'a, I>(&self, points: I) -> Result<(), WriteError>
+    where
+        I: IntoIterator<Item = &'a Point>,
+    {
+        let mut body = String::new();
+        let mut count = 0;
+        for point in points {
+            if count > 0 {
+                body.push('\n');
+            }
+            // Writing to a `String` never fails.
+            let _ = write!(body, "{point}");
+            count += 1;
+            if count == self.batch_size {
+                self.write(mem::take(&mut body)).await?;
+                count = 0;
+            }
+        }
+        if count > 0 {
+            self.write(body).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the URL of the given `path` below the `base` URL.
+fn endpoint(base: &Url, path: &[&str]) -> Url {
+    let mut url = base.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    // `http` and `https` URLs always have path segments.
+    if let Ok(mut segments) = url.path_segments_mut() {
+        let _ = segments.pop_if_empty().extend(path);
+    }
+    url
+}
@@ -0,0 +1,229 @@
+use std::fmt::{self, Write as _};
+
+/// Value of a field of a [`Point`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    /// 64 bit floating point number.
+    ///
+    /// `NaN` and infinite values are rejected by InfluxDB.
+    Float(f64),
+
+    /// Signed 64 bit integer.
+    Integer(i64),
+
+    /// Unsigned 64 bit integer.
+    UInteger(u64),
+
+    /// String.
+    String(String),
+
+    /// Boolean.
+    Boolean(bool),
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<u64> for FieldValue {
+    fn from(value: u64) -> Self {
+        Self::UInteger(value)
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}i"),
+            Self::UInteger(value) => write!(f, "{value}u"),
+            Self::String(value) => {
+                f.write_char('"')?;
+                escape(f, value, &['"', '\\'])?;
+                f.write_char('"')
+            }
+            Self::Boolean(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Single data point which is written using the InfluxDB line protocol.
+///
+/// A [`Point`] needs at least one field. Otherwise InfluxDB rejects the
+/// whole request it is part of.
+///
+/// The [`Display`](fmt::Display) implementation returns the point as a
+/// single line of line protocol.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    measurement: String,
+    tags: Vec<(String, String)>,
+    fields: Vec<(String, FieldValue)>,
+    timestamp: Option<i64>,
+}
+
+impl Point {
+    /// Creates a new [`Point`] for the given `measurement`.
+    #[must_use]
+    pub fn new<T: Into<String>>(measurement: T) -> Self {
+        Self {
+            measurement: measurement.into(),
+            tags: Vec::new(),
+            fields: Vec::new(),
+            timestamp: None,
+        }
+    }
+
+    /// Adds a tag to this [`Point`].
+    #[must_use]
+    pub fn tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Adds a field to this [`Point`].
+    #[must_use]
+    pub fn field<K: Into<String>, V: Into<FieldValue>>(mut self, key: K, value: V) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the timestamp of this [`Point`].
+    ///
+    /// The timestamp is interpreted using the configured
+    /// [`Precision`](crate::Precision). InfluxDB uses the time it received
+    /// the point if no timestamp is set.
+    #[must_use]
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Returns the measurement of this [`Point`].
+    #[must_use]
+    pub fn measurement(&self) -> &str {
+        &self.measurement
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        escape(f, &self.measurement, &[',', ' '])?;
+        for (key, value) in &self.tags {
+            f.write_char(',')?;
+            escape(f, key, &[',', '=', ' '])?;
+            f.write_char('=')?;
+            escape(f, value, &[',', '=', ' '])?;
+        }
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            f.write_char(if i == 0 { ' ' } else { ',' })?;
+            escape(f, key, &[',', '=', ' '])?;
+            write!(f, "={value}")?;
+        }
+        if let Some(timestamp) = self.timestamp {
+            write!(f, " {timestamp}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `value` prefixing every character contained in `special` with a
+/// backslash. Newlines can't be escaped in line protocol and are replaced
+/// by spaces.
+fn escape(f: &mut fmt::Formatter<'_>, value: &str, special: &[char]) -> fmt::Result {
+    for c in value.chars() {
+        let c = if c == '\n' { ' ' } else { c };
+        if special.contains(&c) {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurement_is_escaped() {
+        let point = Point::new("cpu load,total").field("value", 1.5);
+        assert_eq!(point.to_string(), r"cpu\ load\,total value=1.5");
+    }
+
+    #[test]
+    fn equal_sign_in_measurement_is_not_escaped() {
+        let point = Point::new("a=b").field("value", true);
+        assert_eq!(point.to_string(), "a=b value=true");
+    }
+
+    #[test]
+    fn tags_are_escaped() {
+        let point = Point::new("cpu")
+            .tag("host name", "a,b=c")
+            .tag("region", "eu")
+            .field("value", 1_i64);
+        assert_eq!(
+            point.to_string(),
+            r"cpu,host\ name=a\,b\=c,region=eu value=1i"
+        );
+    }
+
+    #[test]
+    fn field_keys_are_escaped() {
+        let point = Point::new("cpu").field("a b", 1_u64).field("c,d=e", false);
+        assert_eq!(point.to_string(), r"cpu a\ b=1u,c\,d\=e=false");
+    }
+
+    #[test]
+    fn string_fields_are_quoted_and_escaped() {
+        let point = Point::new("log").field("message", r#"say "hi" \ bye, a=b"#);
+        assert_eq!(point.to_string(), r#"log message="say \"hi\" \\ bye, a=b""#);
+    }
+
+    #[test]
+    fn newlines_are_replaced_by_escaped_spaces() {
+        let point = Point::new("cpu\nload")
+            .tag("host\nname", "a\nb")
+            .field("message", "line\nbreak");
+        assert_eq!(
+            point.to_string(),
+            r#"cpu\ load,host\ name=a\ b message="line break""#
+        );
+    }
+
+    #[test]
+    fn timestamp_is_appended() {
+        let point = Point::new("cpu")
+            .field("value", -2.0)
+            .field("count", -3_i64)
+            .timestamp(1_700_000_000);
+        assert_eq!(point.to_string(), "cpu value=-2,count=-3i 1700000000");
+    }
+}
@@ -0,0 +1,307 @@
+use std::{collections::HashMap, fmt, path::PathBuf, time::Duration};
+
+use rdkafka::ClientConfig;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// KAFKA__BOOTSTRAP_SERVERS=kafka1:9092,kafka2:9092
+/// KAFKA__SECURITY_PROTOCOL=SaslSsl
+/// KAFKA__SASL_MECHANISM=ScramSha512
+/// KAFKA__SASL_USERNAME=deadpool
+/// KAFKA__SASL_PASSWORD=topsecret
+/// KAFKA__POOL__MAX_SIZE=2
+/// KAFKA__POOL__TIMEOUTS__WAIT__SECS=5
+/// KAFKA__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     kafka: deadpool_kafka::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("kafka.bootstrap_servers")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Brokers used to discover the cluster, e.g. `localhost:9092`.
+    pub bootstrap_servers: Vec<String>,
+
+    /// Client ID reported to the brokers.
+    pub client_id: Option<String>,
+
+    /// Protocol used to communicate with the brokers. See
+    /// [`SecurityProtocol`].
+    pub security_protocol: Option<SecurityProtocol>,
+
+    /// SASL mechanism used for authentication. See [`SaslMechanism`].
+    pub sasl_mechanism: Option<SaslMechanism>,
+
+    /// Username used for SASL authentication.
+    pub sasl_username: Option<String>,
+
+    /// Password used for SASL authentication.
+    pub sasl_password: Option<String>,
+
+    /// PEM file containing the CA certificates used to verify the brokers.
+    pub ssl_ca_location: Option<PathBuf>,
+
+    /// PEM file containing the client certificate used for mutual TLS.
+    pub ssl_certificate_location: Option<PathBuf>,
+
+    /// PEM file containing the private key of the
+    /// [`Config::ssl_certificate_location`].
+    pub ssl_key_location: Option<PathBuf>,
+
+    /// Password of the encrypted [`Config::ssl_key_location`].
+    pub ssl_key_password: Option<String>,
+
+    /// Time a produced message may take until it is delivered
+    /// (`message.timeout.ms`).
+    pub message_timeout: Option<Duration>,
+
+    /// Topic whose metadata is requested when recycling a producer.
+    ///
+    /// Requesting the metadata of a single topic is cheaper than the
+    /// default of requesting the metadata of all topics on clusters with
+    /// many topics. Make sure the topic exists as brokers with
+    /// `auto.create.topics.enable` create missing topics.
+    pub health_check_topic: Option<String>,
+
+    /// Timeout of the metadata request sent when recycling a producer.
+    /// Defaults to 5 seconds.
+    pub health_check_timeout: Option<Duration>,
+
+    /// Timeout for delivering the pending messages of a producer which is
+    /// dropped, e.g. because it failed to be recycled. Defaults to 5
+    /// seconds.
+    pub flush_timeout: Option<Duration>,
+
+    /// Additional [librdkafka properties] which are applied after all
+    /// other fields of this [`Config`], e.g. `linger.ms` or
+    /// `compression.type`.
+    ///
+    /// [librdkafka properties]: https://github.com/confluentinc/librdkafka/blob/master/CONFIGURATION.md
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub properties: HashMap<String, String>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] with the given bootstrap servers.
+    #[must_use]
+    pub fn from_bootstrap_servers<I, T>(bootstrap_servers: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            bootstrap_servers: bootstrap_servers.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns a [`ClientConfig`] which can be used to create producers.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn get_client_config(&self) -> Result<ClientConfig, ConfigError> {
+        if self.bootstrap_servers.is_empty() {
+            return Err(ConfigError::NoBootstrapServers);
+        }
+        let mut config = ClientConfig::new();
+        let _ = config.set("bootstrap.servers", self.bootstrap_servers.join(","));
+        if let Some(client_id) = &self.client_id {
+            let _ = config.set("client.id", client_id);
+        }
+        if let Some(security_protocol) = self.security_protocol {
+            let _ = config.set("security.protocol", security_protocol.as_str());
+        }
+        if let Some(sasl_mechanism) = self.sasl_mechanism {
+            let _ = config.set("sasl.mechanism", sasl_mechanism.as_str());
+        }
+        match (&self.sasl_username, &self.sasl_password) {
+            (Some(username), Some(password)) => {
+                let _ = config
+                    .set("sasl.username", username)
+                    .set("sasl.password", password);
+            }
+            (None, None) => {}
+            _ => return Err(ConfigError::IncompleteCredentials),
+        }
+        let files = [
+            ("ssl.ca.location", &self.ssl_ca_location),
+            ("ssl.certificate.location", &self.ssl_certificate_location),
+            ("ssl.key.location", &self.ssl_key_location),
+        ];
+        for (key, path) in files {
+            if let Some(path) = path {
+                let path = path.to_str().ok_or(ConfigError::InvalidPath(key))?;
+                let _ = config.set(key, path);
+            }
+        }
+        if let Some(ssl_key_password) = &self.ssl_key_password {
+            let _ = config.set("ssl.key.password", ssl_key_password);
+        }
+        if let Some(message_timeout) = self.message_timeout {
+            let _ = config.set(
+                "message.timeout.ms",
+                message_timeout.as_millis().to_string(),
+            );
+        }
+        for (key, value) in &self.properties {
+            let _ = config.set(key, value);
+        }
+        Ok(config)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Protocol used to communicate with the brokers (`security.protocol`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SecurityProtocol {
+    /// Unencrypted and unauthenticated.
+    #[default]
+    Plaintext,
+
+    /// Encrypted using TLS. Requires the `ssl` feature.
+    Ssl,
+
+    /// Unencrypted and authenticated using SASL. Requires the `sasl`
+    /// feature for [`SaslMechanism::Gssapi`].
+    SaslPlaintext,
+
+    /// Encrypted using TLS and authenticated using SASL.
+    SaslSsl,
+}
+
+impl SecurityProtocol {
+    /// Returns the value of this [`SecurityProtocol`] as used by
+    /// librdkafka.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Plaintext => "plaintext",
+            Self::Ssl => "ssl",
+            Self::SaslPlaintext => "sasl_plaintext",
+            Self::SaslSsl => "sasl_ssl",
+        }
+    }
+}
+
+/// SASL mechanism used for authentication (`sasl.mechanism`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SaslMechanism {
+    /// Kerberos. Requires the `sasl` feature.
+    Gssapi,
+
+    /// Username and password in plain text.
+    #[default]
+    Plain,
+
+    /// SCRAM using SHA-256. Requires the `ssl` feature.
+    ScramSha256,
+
+    /// SCRAM using SHA-512. Requires the `ssl` feature.
+    ScramSha512,
+
+    /// OAuth bearer tokens.
+    OAuthBearer,
+}
+
+impl SaslMechanism {
+    /// Returns the value of this [`SaslMechanism`] as used by librdkafka.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Gssapi => "GSSAPI",
+            Self::Plain => "PLAIN",
+            Self::ScramSha256 => "SCRAM-SHA-256",
+            Self::ScramSha512 => "SCRAM-SHA-512",
+            Self::OAuthBearer => "OAUTHBEARER",
+        }
+    }
+}
+
+/// This error is returned if there is something wrong with the Kafka
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigError {
+    /// No [`Config::bootstrap_servers`] were specified.
+    NoBootstrapServers,
+
+    /// Only one of [`Config::sasl_username`] and [`Config::sasl_password`]
+    /// was set.
+    IncompleteCredentials,
+
+    /// The path of the given property is not valid UTF-8.
+    InvalidPath(&'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoBootstrapServers => write!(f, "No bootstrap servers specified"),
+            Self::IncompleteCredentials => {
+                write!(f, "SASL username and password must be configured together")
+            }
+            Self::InvalidPath(key) => write!(f, "Path of `{key}` is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,140 @@
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
+
+use deadpool_keyed::{PoolMap, DEFAULT_IDLE_TIMEOUT};
+
+use crate::{Config, CreatePoolError, Manager, Object, Pool, PoolError, Runtime};
+
+/// Pools of producers keyed by the name of their cluster.
+///
+/// Every cluster has its own [`Config`]. The [`Pool`] of a cluster is
+/// created on first use. Pools which are idle for the idle timeout are
+/// evicted, see [`PoolMap`] for details.
+#[derive(Clone, Debug)]
+pub struct KeyedPool {
+    inner: Arc<KeyedPoolInner>,
+}
+
+struct KeyedPoolInner {
+    configs: HashMap<String, Config>,
+    runtime: Option<Runtime>,
+    pools: PoolMap<String, Manager>,
+}
+
+// Implemented manually to not leak the passwords of the configs.
+impl fmt::Debug for KeyedPoolInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyedPoolInner")
+            .field("clusters", &self.configs.keys().collect::<Vec<_>>())
+            .field("runtime", &self.runtime)
+            .field("pools", &self.pools)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyedPool {
+    /// Creates a new [`KeyedPool`] using the given [`Config`] per cluster
+    /// which evicts pools after the [`DEFAULT_IDLE_TIMEOUT`] of 5 minutes.
+    #[must_use]
+    pub fn new(configs: HashMap<String, Config>, runtime: Option<Runtime>) -> Self {
+        Self::with_idle_timeout(configs, runtime, Some(DEFAULT_IDLE_TIMEOUT))
+    }
+
+    /// Creates a new [`KeyedPool`] using the given [`Config`] per cluster
+    /// which evicts pools after the given `idle_timeout`. Pools are never
+    /// evicted if it is [`None`].
+    #[must_use]
+    pub fn with_idle_timeout(
+        configs: HashMap<String, Config>,
+        runtime: Option<Runtime>,
+        idle_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(KeyedPoolInner {
+                configs,
+                runtime,
+                pools: PoolMap::new(idle_timeout),
+            }),
+        }
+    }
+
+    /// Returns the [`Pool`] for the given `cluster` and creates it if
+    /// necessary.
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub fn pool(&self, cluster: &str) -> Result<Pool, KeyedPoolError> {
+        self.inner.pools.get_or_try_insert_with(cluster, || {
+            let config = self
+                .inner
+                .configs
+                .get(cluster)
+                .ok_or_else(|| KeyedPoolError::UnknownCluster(cluster.to_owned()))?;
+            config
+                .create_pool(self.inner.runtime)
+                .map_err(KeyedPoolError::Create)
+        })
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the given `cluster`.
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn get(&self, cluster: &str) -> Result<Object, KeyedPoolError> {
+        let pool = self.pool(cluster)?;
+        pool.get().await.map_err(KeyedPoolError::Pool)
+    }
+
+    /// Closes the [`Pool`] of the given `cluster`. A new [`Pool`] is
+    /// created on next use.
+    pub fn close(&self, cluster: &str) {
+        if let Some(pool) = self.inner.pools.remove(cluster) {
+            pool.close();
+        }
+    }
+
+    /// Removes the pools which are idle. See [`PoolMap::evict_idle()`].
+    pub fn evict_idle(&self) {
+        self.inner.pools.evict_idle();
+    }
+
+    /// Returns the names of all configured clusters.
+    #[must_use]
+    pub fn clusters(&self) -> Vec<String> {
+        self.inner.configs.keys().cloned().collect()
+    }
+}
+
+/// Possible errors returned by a [`KeyedPool`].
+#[derive(Debug)]
+pub enum KeyedPoolError {
+    /// No [`Config`] exists for the given cluster.
+    UnknownCluster(String),
+
+    /// The [`Pool`] for the cluster could not be created.
+    Create(CreatePoolError),
+
+    /// No [`Object`] could be retrieved from the [`Pool`].
+    Pool(PoolError),
+}
+
+impl fmt::Display for KeyedPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCluster(cluster) => write!(f, "Unknown cluster: {cluster}"),
+            Self::Create(e) => write!(f, "Failed to create pool: {e}"),
+            Self::Pool(e) => write!(f, "Failed to get producer: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for KeyedPoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnknownCluster(_) => None,
+            Self::Create(e) => Some(e),
+            Self::Pool(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod keyed;
+
+use std::{fmt, ops::Deref, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use rdkafka::{
+    error::KafkaError,
+    producer::{FutureProducer, Producer},
+    ClientConfig,
+};
+
+pub use rdkafka;
+
+pub use self::{
+    config::{Config, ConfigError, SaslMechanism, SecurityProtocol},
+    keyed::{KeyedPool, KeyedPoolError},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "rdkafka",
+    Manager,
+    managed::Object<Manager>,
+    KafkaError,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<KafkaError>;
+
+/// Default timeout of the metadata request sent when recycling a producer.
+const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default timeout for delivering the pending messages of a dropped
+/// producer.
+const DEFAULT_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// [`Manager`] for creating and recycling [`FutureProducer`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    client_config: ClientConfig,
+    health_check_topic: Option<String>,
+    health_check_timeout: Duration,
+    flush_timeout: Duration,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self {
+            client_config: config.get_client_config()?,
+            health_check_topic: config.health_check_topic.clone(),
+            health_check_timeout: config
+                .health_check_timeout
+                .unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT),
+            flush_timeout: config.flush_timeout.unwrap_or(DEFAULT_FLUSH_TIMEOUT),
+        })
+    }
+}
+
+// Implemented manually to not leak the credentials of the `ClientConfig`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field(
+                "bootstrap_servers",
+                &self.client_config.get("bootstrap.servers"),
+            )
+            .field("health_check_topic", &self.health_check_topic)
+            .field("health_check_timeout", &self.health_check_timeout)
+            .field("flush_timeout", &self.flush_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = ProducerWrapper;
+    type Error = KafkaError;
+
+    async fn create(&self) -> Result<ProducerWrapper, KafkaError> {
+        Ok(ProducerWrapper {
+            producer: self.client_config.create()?,
+            flush_timeout: self.flush_timeout,
+        })
+    }
+
+    async fn recycle(&self, producer: &mut ProducerWrapper, _: &Metrics) -> RecycleResult {
+        let producer = producer.producer.clone();
+        let topic = self.health_check_topic.clone();
+        let timeout = self.health_check_timeout;
+        // `fetch_metadata()` blocks until the brokers answered.
+        let metadata = tokio::task::spawn_blocking(move || {
+            producer.client().fetch_metadata(topic.as_deref(), timeout)
+        })
+        .await
+        .map_err(|e| RecycleError::message(format!("Health check failed: {e}")))??;
+        if metadata.brokers().is_empty() {
+            return Err(RecycleError::message("No brokers available"));
+        }
+        Ok(())
+    }
+}
+
+/// Wrapper around [`FutureProducer`].
+///
+/// Dropping the wrapper, e.g. because the producer failed to be recycled,
+/// flushes the messages which were not delivered yet on a blocking thread
+/// for up to the [`Config::flush_timeout`]. Messages which are still not
+/// delivered after that are lost.
+pub struct ProducerWrapper {
+    producer: FutureProducer,
+    flush_timeout: Duration,
+}
+
+// Implemented manually as `FutureProducer` doesn't implement `Debug`.
+impl fmt::Debug for ProducerWrapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProducerWrapper")
+            .field("in_flight_count", &self.producer.in_flight_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for ProducerWrapper {
+    type Target = FutureProducer;
+
+    fn deref(&self) -> &FutureProducer {
+        &self.producer
+    }
+}
+
+impl Drop for ProducerWrapper {
+    fn drop(&mut self) {
+        // librdkafka discards the queued messages when the last reference
+        // to the producer is dropped.
+        if self.producer.in_flight_count() == 0 {
+            return;
+        }
+        let producer = self.producer.clone();
+        let timeout = self.flush_timeout;
+        let flush = move || {
+            let _ = producer.flush(timeout);
+        };
+        // `flush()` blocks until the messages are delivered.
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => drop(handle.spawn_blocking(flush)),
+            Err(_) => flush(),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod target;
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    sync::{Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+use deadpool::managed::{Manager, Pool};
+
+pub use self::target::Target;
+
+/// Default time after which the [`Pool`] of a key which is not used is
+/// evicted from a [`PoolMap`].
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Map of [`Pool`]s keyed by `K` which creates the [`Pool`] of a key on
+/// first use and evicts idle pools.
+///
+/// A [`Pool`] is idle if it was not retrieved from the map for the idle
+/// timeout and none of its objects is in use.
+pub struct PoolMap<K, M: Manager> {
+    pools: RwLock<HashMap<K, Entry<M>>>,
+    idle_timeout: Option<Duration>,
+}
+
+struct Entry<M: Manager> {
+    pool: Pool<M>,
+    last_used: Mutex<Instant>,
+}
+
+impl<M: Manager> Entry<M> {
+    /// Marks this entry as used and returns its [`Pool`].
+    fn touch(&self) -> Pool<M> {
+        *self.last_used.lock().unwrap() = Instant::now();
+        self.pool.clone()
+    }
+
+    fn is_idle(&self, idle_timeout: Duration, now: Instant) -> bool {
+        let last_used = *self.last_used.lock().unwrap();
+        let status = self.pool.status();
+        now.saturating_duration_since(last_used) >= idle_timeout
+            && status.available == status.size
+            && status.waiting == 0
+    }
+}
+
+// Implemented manually as `Pool` only implements `Debug` if the objects do.
+impl<K: fmt::Debug, M: Manager> fmt::Debug for PoolMap<K, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolMap")
+            .field("keys", &self.pools.read().unwrap().keys())
+            .field("idle_timeout", &self.idle_timeout)
+            .finish()
+    }
+}
+
+impl<K: Eq + Hash, M: Manager> Default for PoolMap<K, M> {
+    fn default() -> Self {
+        Self::new(Some(DEFAULT_IDLE_TIMEOUT))
+    }
+}
+
+impl<K: Eq + Hash, M: Manager> PoolMap<K, M> {
+    /// Creates a new empty [`PoolMap`] evicting pools which are idle for
+    /// the given `idle_timeout`. Pools are never evicted if it is [`None`].
+    #[must_use]
+    pub fn new(idle_timeout: Option<Duration>) -> Self {
+        Self {
+            pools: RwLock::new(HashMap::new()),
+            idle_timeout,
+        }
+    }
+
+    /// Returns the idle timeout of this [`PoolMap`].
+    #[must_use]
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout
+    }
+
+    /// Returns the [`Pool`] of the given `key` or inserts the [`Pool`]
+    /// returned by `create` if there is none yet.
+    ///
+    /// Idle pools are evicted before a new [`Pool`] is inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `create` if it fails.
+    pub fn get_or_try_insert_with<Q, E, F>(&self, key: &Q, create: F) -> Result<Pool<M>, E>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> Result<Pool<M>, E>,
+    {
+        if let Some(entry) = self.pools.read().unwrap().get(key) {
+            return Ok(entry.touch());
+        }
+        let mut pools = self.pools.write().unwrap();
+        if let Some(entry) = pools.get(key) {
+            return Ok(entry.touch());
+        }
+        let pool = create()?;
+        if let Some(idle_timeout) = self.idle_timeout {
+            let now = Instant::now();
+            pools.retain(|_, entry| !entry.is_idle(idle_timeout, now));
+        }
+        let _ = pools.insert(
+            key.to_owned(),
+            Entry {
+                pool: pool.clone(),
+                last_used: Mutex::new(Instant::now()),
+            },
+        );
+        Ok(pool)
+    }
+
+    /// Removes the [`Pool`] of the given `key` and returns it.
+    pub fn remove<Q>(&self, key: &Q) -> Option<Pool<M>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.pools.write().unwrap().remove(key)?;
+        Some(entry.pool)
+    }
+
+    /// Removes all idle pools. See [`PoolMap`] for details.
+    ///
+    /// Call this method periodically if new keys are rarely used, as
+    /// otherwise idle pools are only evicted when a new [`Pool`] is
+    /// created.
+    pub fn evict_idle(&self) {
+        let Some(idle_timeout) = self.idle_timeout else {
+            return;
+        };
+        let now = Instant::now();
+        self.pools
+            .write()
+            .unwrap()
+            .retain(|_, entry| !entry.is_idle(idle_timeout, now));
+    }
+
+    /// Returns the keys of all pools of this [`PoolMap`].
+    #[must_use]
+    pub fn keys(&self) -> Vec<K>
+    where
+        K: Clone,
+    {
+        self.pools.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Returns all pools of this [`PoolMap`].
+    #[must_use]
+    pub fn pools(&self) -> Vec<Pool<M>> {
+        let pools = self.pools.read().unwrap();
+        pools.values().map(|entry| entry.pool.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use deadpool::managed::{Metrics, RecycleResult};
+
+    use super::*;
+
+    struct TestManager;
+
+    impl Manager for TestManager {
+        type Type = ();
+        type Error = Infallible;
+
+        async fn create(&self) -> Result<(), Infallible> {
+            Ok(())
+        }
+
+        async fn recycle(&self, _: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+            Ok(())
+        }
+    }
+
+    fn pool() -> Result<Pool<TestManager>, Infallible> {
+        Ok(Pool::builder(TestManager).max_size(2).build().unwrap())
+    }
+
+    fn keys(map: &PoolMap<String, TestManager>) -> Vec<String> {
+        let mut keys = map.keys();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn pool_is_created_once() {
+        let map = PoolMap::<String, TestManager>::new(None);
+        let mut created = 0;
+        for _ in 0..3 {
+            let _ = map
+                .get_or_try_insert_with("a", || {
+                    created += 1;
+                    pool()
+                })
+                .unwrap();
+        }
+        assert_eq!(created, 1);
+        assert_eq!(keys(&map), ["a"]);
+    }
+
+    #[test]
+    fn failed_create_is_not_inserted() {
+        let map = PoolMap::<String, TestManager>::new(None);
+        let result = map.get_or_try_insert_with("a", || Err("invalid"));
+        assert_eq!(result.err(), Some("invalid"));
+        assert!(map.keys().is_empty());
+    }
+
+    #[test]
+    fn removed_pool_is_returned() {
+        let map = PoolMap::<String, TestManager>::new(None);
+        let _ = map.get_or_try_insert_with("a", pool).unwrap();
+        assert!(map.remove("a").is_some());
+        assert!(map.remove("a").is_none());
+        assert!(map.pools().is_empty());
+    }
+
+    #[test]
+    fn pools_are_kept_without_idle_timeout() {
+        let map = PoolMap::<String, TestManager>::new(None);
+        let _ = map.get_or_try_insert_with("a", pool).unwrap();
+        let _ = map.get_or_try_insert_with("b", pool).unwrap();
+        map.evict_idle();
+        assert_eq!(keys(&map), ["a", "b"]);
+    }
+
+    #[test]
+    fn idle_pools_are_evicted() {
+        let map = PoolMap::<String, TestManager>::new(Some(Duration::ZERO));
+        let _ = map.get_or_try_insert_with("a", pool).unwrap();
+        let _ = map.get_or_try_insert_with("b", pool).unwrap();
+        assert_eq!(keys(&map), ["b"]);
+        map.evict_idle();
+        assert!(map.keys().is_empty());
+    }
+
+    #[test]
+    fn recently_used_pools_are_kept() {
+        let map = PoolMap::<String, TestManager>::new(Some(Duration::from_secs(60)));
+        let _ = map.get_or_try_insert_with("a", pool).unwrap();
+        let _ = map.get_or_try_insert_with("b", pool).unwrap();
+        map.evict_idle();
+        assert_eq!(keys(&map), ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn pools_in_use_are_kept() {
+        let map = PoolMap::<String, TestManager>::new(Some(Duration::ZERO));
+        let obj = map.get_or_try_insert_with("a", pool).unwrap().get().await;
+        map.evict_idle();
+        assert_eq!(keys(&map), ["a"]);
+        drop(obj);
+        map.evict_idle();
+        assert!(map.keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn evicted_pools_are_not_closed() {
+        let map = PoolMap::<String, TestManager>::new(Some(Duration::ZERO));
+        let pool = map.get_or_try_insert_with("a", pool).unwrap();
+        map.evict_idle();
+        assert!(!pool.is_closed());
+        assert!(pool.get().await.is_ok());
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+/// Host, port and user identifying the connections of a pool, e.g. the
+/// key of a [`PoolMap`](crate::PoolMap) of SSH sessions or FTP connections.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Target {
+    host: String,
+    port: u16,
+    user: String,
+}
+
+impl Target {
+    /// Creates a new [`Target`].
+    #[must_use]
+    pub fn new<H: Into<String>, U: Into<String>>(host: H, port: u16, user: U) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            user: user.into(),
+        }
+    }
+
+    /// Returns the host of this [`Target`].
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port of this [`Target`].
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the user of this [`Target`].
+    #[must_use]
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "{}@[{}]:{}", self.user, self.host, self.port)
+        } else {
+            write!(f, "{}@{}:{}", self.user, self.host, self.port)
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::{fmt, time::Duration};
+
+use ldap3::LdapConnSettings;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// LDAP__URL=ldaps://ldap.example.com
+/// LDAP__BIND_DN=cn=service,dc=example,dc=com
+/// LDAP__BIND_PASSWORD=topsecret
+/// LDAP__MANAGER__RECYCLING_METHOD=Rebind
+/// LDAP__POOL__MAX_SIZE=16
+/// LDAP__POOL__TIMEOUTS__WAIT__SECS=5
+/// LDAP__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     ldap: deadpool_ldap::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// LDAP URL, e.g. `ldap://localhost:389` or `ldaps://ldap.example.com`.
+    pub url: Option<String>,
+
+    /// DN used for a simple bind after connecting. Connections stay
+    /// anonymous if this is not set.
+    pub bind_dn: Option<String>,
+
+    /// Password used for the simple bind.
+    pub bind_password: Option<String>,
+
+    /// Timeout for establishing the connection.
+    pub conn_timeout: Option<Duration>,
+
+    /// Upgrade `ldap://` connections to TLS using the StartTLS extended
+    /// operation.
+    #[cfg(any(feature = "tls-native", feature = "tls-rustls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tls-native", feature = "tls-rustls"))))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub starttls: bool,
+
+    /// Disable the verification of the server certificate.
+    ///
+    /// **Important:** This makes the connection vulnerable to
+    /// man-in-the-middle attacks and should only be used for testing.
+    #[cfg(any(feature = "tls-native", feature = "tls-rustls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tls-native", feature = "tls-rustls"))))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_tls_verify: bool,
+
+    /// [`Manager`] configuration.
+    pub manager: Option<ManagerConfig>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] with the given URL.
+    #[must_use]
+    pub fn from_url<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns [`LdapConnSettings`] which can be used to connect to the
+    /// server.
+    #[must_use]
+    pub fn get_conn_settings(&self) -> LdapConnSettings {
+        let mut settings = LdapConnSettings::new();
+        if let Some(conn_timeout) = self.conn_timeout {
+            settings = settings.set_conn_timeout(conn_timeout);
+        }
+        #[cfg(any(feature = "tls-native", feature = "tls-rustls"))]
+        {
+            settings = settings
+                .set_starttls(self.starttls)
+                .set_no_tls_verify(self.no_tls_verify);
+        }
+        settings
+    }
+
+    /// Returns [`ManagerConfig`] which can be used to construct a
+    /// [`Manager`] instance.
+    #[must_use]
+    pub fn get_manager_config(&self) -> ManagerConfig {
+        self.manager.unwrap_or_default()
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`WhoAmI`] which verifies the connection without
+/// changing its bound identity.
+///
+/// [`WhoAmI`]: RecyclingMethod::WhoAmI
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Send a "Who am I?" extended operation (RFC 4532).
+    #[default]
+    WhoAmI,
+
+    /// Bind again using [`Config::bind_dn`] (or anonymously if none is
+    /// configured) or the hook set by [`Manager::with_rebind_hook()`].
+    ///
+    /// Use this if connections of the pool are used to verify user
+    /// credentials via `simple_bind` as this restores the identity of the
+    /// service account before the connection is handed out again.
+    Rebind,
+}
+
+/// This error is returned if there is something wrong with the LDAP
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigError {
+    /// No [`Config::url`] was specified.
+    MissingUrl,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUrl => write!(f, "No URL specified"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
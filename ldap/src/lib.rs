@@ -0,0 +1,123 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::{fmt, future::Future, pin::Pin};
+
+use deadpool::managed::{self, RecycleError};
+use ldap3::{exop::WhoAmI, Ldap, LdapConnAsync, LdapError};
+
+pub use ldap3;
+
+pub use self::config::{Config, ConfigError, ManagerConfig, RecyclingMethod};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "ldap3",
+    Manager,
+    managed::Object<Manager>,
+    LdapError,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<LdapError>;
+
+/// Future returned by a rebind hook. See [`Manager::with_rebind_hook()`].
+pub type RebindFuture<'a> = Pin<Box<dyn Future<Output = Result<(), LdapError>> + Send + 'a>>;
+
+type RebindHook = Box<dyn for<'a> Fn(&'a mut Ldap) -> RebindFuture<'a> + Send + Sync>;
+
+/// [`Manager`] for creating and recycling [`ldap3::Ldap`] connections.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    url: String,
+    config: Config,
+    rebind_hook: Option<RebindHook>,
+}
+
+// Implemented manually to not leak the password and as the rebind hook
+// doesn't implement `Debug`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("url", &self.url)
+            .field("bind_dn", &self.config.bind_dn)
+            .field("manager", &self.config.manager)
+            .field("rebind_hook", &self.rebind_hook.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::MissingUrl`] if no [`Config::url`] is set.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self {
+            url: config.url.clone().ok_or(ConfigError::MissingUrl)?,
+            config: config.clone(),
+            rebind_hook: None,
+        })
+    }
+
+    /// Sets a hook which binds the connections instead of the simple bind
+    /// using the [`Config::bind_dn`], e.g. to use a SASL mechanism or
+    /// credentials which are rotated at runtime.
+    ///
+    /// The hook is called right after connecting and, if the
+    /// [`RecyclingMethod::Rebind`] is used, before a connection is handed
+    /// out again.
+    #[must_use]
+    pub fn with_rebind_hook<F>(mut self, hook: F) -> Self
+    where
+        F: for<'a> Fn(&'a mut Ldap) -> RebindFuture<'a> + Send + Sync + 'static,
+    {
+        self.rebind_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Binds the connection using the rebind hook if one is set.
+    /// Otherwise the configured [`Config::bind_dn`] is used or the
+    /// connection is bound anonymously if none is configured.
+    async fn bind(&self, ldap: &mut Ldap) -> Result<(), LdapError> {
+        if let Some(rebind_hook) = &self.rebind_hook {
+            return rebind_hook(ldap).await;
+        }
+        let bind_dn = self.config.bind_dn.as_deref().unwrap_or_default();
+        let password = self.config.bind_password.as_deref().unwrap_or_default();
+        let _ = ldap.simple_bind(bind_dn, password).await?.success()?;
+        Ok(())
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Ldap;
+    type Error = LdapError;
+
+    async fn create(&self) -> Result<Ldap, LdapError> {
+        let (conn, mut ldap) =
+            LdapConnAsync::with_settings(self.config.get_conn_settings(), &self.url).await?;
+        ldap3::drive!(conn);
+        if self.rebind_hook.is_some() || self.config.bind_dn.is_some() {
+            self.bind(&mut ldap).await?;
+        }
+        Ok(ldap)
+    }
+
+    async fn recycle(&self, ldap: &mut Ldap, _: &Metrics) -> RecycleResult {
+        if ldap.is_closed() {
+            return Err(RecycleError::message("Connection closed"));
+        }
+        match self.config.get_manager_config().recycling_method {
+            RecyclingMethod::WhoAmI => {
+                let _ = ldap.extended(WhoAmI).await?.success()?;
+            }
+            RecyclingMethod::Rebind => self.bind(ldap).await?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fmt, sync::Arc};
+
+use crate::{Object, Pool, PoolError};
+
+/// Number of points each server occupies on the hash ring.
+const POINTS_PER_SERVER: u32 = 160;
+
+/// Multiple memcached servers with one [`Pool`] per server.
+///
+/// Keys are distributed across the servers using consistent hashing, so
+/// adding or removing a server only moves the keys of a small part of the
+/// ring to a different server. Every application using the same list of
+/// servers maps keys to the same server.
+#[derive(Clone)]
+pub struct Cluster {
+    pools: Vec<Pool>,
+    ring: Arc<[(u64, usize)]>,
+}
+
+impl Cluster {
+    /// Creates a new [`Cluster`] from the given pools.
+    ///
+    /// The address of the [`Manager`](crate::Manager) of each [`Pool`] is
+    /// used to determine its position on the hash ring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pools` is empty.
+    #[must_use]
+    pub fn new(pools: Vec<Pool>) -> Self {
+        assert!(!pools.is_empty(), "A cluster needs at least one pool");
+        let mut ring = pools
+            .iter()
+            .enumerate()
+            .flat_map(|(index, pool)| {
+                let addr = pool.manager().addr().to_owned();
+                (0..POINTS_PER_SERVER).map(move |point| (hash(format!("{addr}-{point}")), index))
+            })
+            .collect::<Vec<_>>();
+        ring.sort_unstable();
+        Self {
+            pools,
+            ring: ring.into(),
+        }
+    }
+
+    /// Returns the [`Pool`] of the server responsible for the given `key`.
+    pub fn pool<K: AsRef<[u8]>>(&self, key: K) -> &Pool {
+        let hash = hash(key);
+        let point = self.ring.partition_point(|&(h, _)| h < hash);
+        let (_, index) = self.ring[point % self.ring.len()];
+        &self.pools[index]
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the server responsible
+    /// for the given `key`.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn get<K: AsRef<[u8]>>(&self, key: K) -> Result<Object, PoolError> {
+        self.pool(key).get().await
+    }
+
+    /// Returns the pools of all servers of this [`Cluster`].
+    #[must_use]
+    pub fn pools(&self) -> &[Pool] {
+        &self.pools
+    }
+}
+
+// Implemented manually as the memcached client doesn't implement `Debug`.
+impl fmt::Debug for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let servers = self
+            .pools
+            .iter()
+            .map(|pool| pool.manager().addr())
+            .collect::<Vec<_>>();
+        f.debug_struct("Cluster")
+            .field("servers", &servers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stable 64-bit FNV-1a hash with an additional finalizer for a better
+/// distribution of similar inputs.
+fn hash<K: AsRef<[u8]>>(key: K) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in key.as_ref() {
+        h ^= u64::from(byte);
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::Manager;
+
+    use super::*;
+
+    fn cluster(addrs: &[&str]) -> Cluster {
+        let pools = addrs
+            .iter()
+            .map(|addr| Pool::builder(Manager::new(*addr)).build().unwrap())
+            .collect();
+        Cluster::new(pools)
+    }
+
+    fn server(cluster: &Cluster, key: &str) -> String {
+        cluster.pool(key).manager().addr().to_owned()
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        // Changing the hash would move the keys of every deployed cluster.
+        assert_eq!(hash(""), 0xefd0_1f60_ba99_2926);
+        assert_eq!(hash("deadpool"), 0xfcc8_1f2f_5987_4197);
+    }
+
+    #[test]
+    fn keys_are_distributed_evenly() {
+        let cluster = cluster(&["a:11211", "b:11211", "c:11211", "d:11211"]);
+        let mut counts = HashMap::<String, usize>::new();
+        for i in 0..10_000 {
+            *counts
+                .entry(server(&cluster, &format!("key-{i}")))
+                .or_default() += 1;
+        }
+        assert_eq!(counts.len(), 4);
+        for (addr, count) in counts {
+            assert!((1_500..=3_500).contains(&count), "{addr} got {count} keys");
+        }
+    }
+
+    #[test]
+    fn removing_a_server_only_moves_its_keys() {
+        let before = cluster(&["a:11211", "b:11211", "c:11211"]);
+        let after = cluster(&["a:11211", "c:11211"]);
+        for i in 0..1_000 {
+            let key = format!("key-{i}");
+            let old = server(&before, &key);
+            if old != "b:11211" {
+                assert_eq!(server(&after, &key), old, "{key} moved");
+            }
+        }
+    }
+
+    #[test]
+    fn order_of_pools_does_not_matter() {
+        let a = cluster(&["a:11211", "b:11211", "c:11211"]);
+        let b = cluster(&["c:11211", "a:11211", "b:11211"]);
+        for i in 0..1_000 {
+            let key = format!("key-{i}");
+            assert_eq!(server(&a, &key), server(&b, &key));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one pool")]
+    fn empty_cluster_panics() {
+        let _ = Cluster::new(Vec::new());
+    }
+}
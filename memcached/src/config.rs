@@ -0,0 +1,195 @@
+use std::fmt;
+
+#[cfg(feature = "rustls")]
+use crate::TlsConfig;
+use crate::{Cluster, CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// MEMCACHED__SERVERS=cache1:11211,cache2:11211
+/// MEMCACHED__POOL__MAX_SIZE=16
+/// MEMCACHED__POOL__TIMEOUTS__WAIT__SECS=5
+/// MEMCACHED__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     memcached: deadpool_memcached::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("memcached.servers")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Addresses (`host:port`) of the memcached servers.
+    pub servers: Vec<String>,
+
+    /// Username used for authentication.
+    ///
+    /// memcached does not support SASL via the text protocol. The
+    /// credentials are checked by the server if it was started with an
+    /// authentication file (`memcached -Y <authfile>`).
+    pub username: Option<String>,
+
+    /// Password used for authentication. See [`Config::username`].
+    pub password: Option<String>,
+
+    /// TLS configuration. Plain TCP is used if this is not set.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration.
+    ///
+    /// When creating a [`Cluster`] this configuration is used for every
+    /// server.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given memcached servers.
+    #[must_use]
+    pub fn from_servers<I, S>(servers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            servers: servers.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::NoServers`] or
+    /// [`ConfigError::MultipleServers`] unless exactly one server is
+    /// configured. Use [`Config::create_cluster()`] for multiple servers.
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        match self.servers.as_slice() {
+            [] => Err(ConfigError::NoServers),
+            [server] => self.server_builder(server),
+            _ => Err(ConfigError::MultipleServers),
+        }
+    }
+
+    /// Creates a new [`Cluster`] with one [`Pool`] per configured server.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_cluster(&self, runtime: Option<Runtime>) -> Result<Cluster, CreatePoolError> {
+        if self.servers.is_empty() {
+            return Err(CreatePoolError::Config(ConfigError::NoServers));
+        }
+        let pools = self
+            .servers
+            .iter()
+            .map(|server| {
+                let mut builder = self
+                    .server_builder(server)
+                    .map_err(CreatePoolError::Config)?;
+                if let Some(runtime) = runtime {
+                    builder = builder.runtime(runtime);
+                }
+                builder.build().map_err(CreatePoolError::Build)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Cluster::new(pools))
+    }
+
+    fn server_builder(&self, server: &str) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(server, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the memcached
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No server was configured.
+    NoServers,
+
+    /// More than one server was configured when creating a single [`Pool`].
+    MultipleServers,
+
+    /// Only one of [`Config::username`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoServers => write!(f, "No server configured"),
+            Self::MultipleServers => write!(
+                f,
+                "Multiple servers configured. Use `Config::create_cluster()` instead."
+            ),
+            Self::IncompleteCredentials => {
+                write!(f, "Username and password must be configured together")
+            }
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,174 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod cluster;
+mod config;
+mod stream;
+#[cfg(feature = "rustls")]
+mod tls;
+
+use std::{fmt, io};
+
+use deadpool::managed;
+use memcache_async::ascii::Protocol;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+pub use memcache_async;
+
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+pub use self::tls::TlsConfig;
+pub use self::{
+    cluster::Cluster,
+    config::{Config, ConfigError},
+    stream::Stream,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "memcache_async",
+    Manager,
+    managed::Object<Manager>,
+    io::Error,
+    ConfigError
+);
+
+/// Type of the wrapped memcached client.
+pub type Connection = Protocol<Stream>;
+
+type RecycleResult = managed::RecycleResult<io::Error>;
+
+/// [`Manager`] for creating and recycling memcached [`Connection`]s to a
+/// single server.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    addr: String,
+    credentials: Option<(String, String)>,
+    #[cfg(feature = "rustls")]
+    tls: Option<tls::Connector>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] connecting to the given `addr`
+    /// (`host:port`) without authentication and TLS.
+    #[must_use]
+    pub fn new<S: Into<String>>(addr: S) -> Self {
+        Self {
+            addr: addr.into(),
+            credentials: None,
+            #[cfg(feature = "rustls")]
+            tls: None,
+        }
+    }
+
+    /// Creates a new [`Manager`] connecting to the given `addr`
+    /// (`host:port`) using the credentials and TLS settings of the given
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config<S: Into<String>>(addr: S, config: &Config) -> Result<Self, ConfigError> {
+        let addr = addr.into();
+        let credentials = match (&config.username, &config.password) {
+            (Some(username), Some(password)) => Some((username.clone(), password.clone())),
+            (None, None) => None,
+            _ => return Err(ConfigError::IncompleteCredentials),
+        };
+        Ok(Self {
+            #[cfg(feature = "rustls")]
+            tls: config
+                .tls
+                .as_ref()
+                .map(|tls| tls.connector(host(&addr)))
+                .transpose()?,
+            addr,
+            credentials,
+        })
+    }
+
+    /// Returns the address of the server this [`Manager`] connects to.
+    #[must_use]
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    async fn connect(&self) -> io::Result<Stream> {
+        let tcp = TcpStream::connect(&self.addr).await?;
+        tcp.set_nodelay(true)?;
+        #[cfg(feature = "rustls")]
+        if let Some(tls) = &self.tls {
+            return tls.connect(tcp).await;
+        }
+        Ok(Stream::Tcp(tcp))
+    }
+}
+
+// Implemented manually to not leak the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let username = self.credentials.as_ref().map(|(username, _)| username);
+        let mut f = f.debug_struct("Manager");
+        let _ = f.field("addr", &self.addr).field("username", &username);
+        #[cfg(feature = "rustls")]
+        let _ = f.field("tls", &self.tls);
+        f.finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = io::Error;
+
+    async fn create(&self) -> Result<Connection, io::Error> {
+        let mut stream = self.connect().await?;
+        if let Some((username, password)) = &self.credentials {
+            authenticate(&mut stream, username, password).await?;
+        }
+        Ok(Protocol::new(stream))
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        let _ = conn.version().await?;
+        Ok(())
+    }
+}
+
+/// Authenticates using the ASCII protocol authentication of memcached
+/// (enabled via `memcached -Y <authfile>`).
+///
+/// The text protocol does not support SASL. Instead the credentials are
+/// sent as the value of an arbitrary `set` command.
+async fn authenticate(stream: &mut Stream, username: &str, password: &str) -> io::Result<()> {
+    let token = format!("{username} {password}");
+    let command = format!("set deadpool 0 0 {}\r\n{token}\r\n", token.len());
+    stream.write_all(command.as_bytes()).await?;
+    stream.flush().await?;
+    // Read byte by byte so no data following the response is consumed.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n") {
+        response.push(stream.read_u8().await?);
+    }
+    if response == b"STORED\r\n" {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "Authentication failed: {}",
+                String::from_utf8_lossy(&response).trim_end()
+            ),
+        ))
+    }
+}
+
+/// Returns the host part of a `host:port` address.
+#[cfg(feature = "rustls")]
+fn host(addr: &str) -> &str {
+    let host = addr.rsplit_once(':').map_or(addr, |(host, _)| host);
+    host.trim_start_matches('[').trim_end_matches(']')
+}
@@ -0,0 +1,61 @@
+use std::{fmt, io, path::PathBuf, sync::Arc};
+
+use tokio::net::TcpStream;
+use tokio_rustls::{rustls::pki_types::ServerName, TlsConnector};
+
+use crate::{ConfigError, Stream};
+
+/// TLS configuration.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the server.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<PathBuf>,
+
+    /// Name used for verifying the server certificate.
+    ///
+    /// Defaults to the host of the server address.
+    pub server_name: Option<String>,
+}
+
+impl TlsConfig {
+    pub(crate) fn connector(&self, host: &str) -> Result<Connector, ConfigError> {
+        let config = deadpool_rustls::client_config(self.ca_file.as_deref()).map_err(tls_error)?;
+        let server_name = self.server_name.as_deref().unwrap_or(host).to_owned();
+        Ok(Connector {
+            connector: TlsConnector::from(Arc::new(config)),
+            server_name: ServerName::try_from(server_name).map_err(tls_error)?,
+        })
+    }
+}
+
+fn tls_error(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> ConfigError {
+    ConfigError::Tls(e.into())
+}
+
+/// Ready to use TLS settings of a [`Manager`](crate::Manager).
+#[derive(Clone)]
+pub(crate) struct Connector {
+    connector: TlsConnector,
+    server_name: ServerName<'static>,
+}
+
+impl Connector {
+    pub(crate) async fn connect(&self, tcp: TcpStream) -> io::Result<Stream> {
+        let stream = self
+            .connector
+            .connect(self.server_name.clone(), tcp)
+            .await?;
+        Ok(Stream::Tls(Box::new(stream)))
+    }
+}
+
+impl fmt::Debug for Connector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connector")
+            .field("server_name", &self.server_name)
+            .finish_non_exhaustive()
+    }
+}
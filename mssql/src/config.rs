@@ -0,0 +1,319 @@
+use std::fmt;
+
+use tiberius::{AuthMethod, EncryptionLevel};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// MSSQL__HOST=mssql.example.com
+/// MSSQL__DATABASE=deadpool
+/// MSSQL__USER=deadpool
+/// MSSQL__PASSWORD=topsecret
+/// MSSQL__ENCRYPTION=Required
+/// MSSQL__POOL__MAX_SIZE=16
+/// MSSQL__POOL__TIMEOUTS__WAIT__SECS=5
+/// MSSQL__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     mssql: deadpool_mssql::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// ADO.NET connection string, e.g.
+    /// `server=tcp:localhost,1433;user=sa;password=...`.
+    ///
+    /// All other fields of this [`Config`] override the values of the
+    /// connection string.
+    pub connection_string: Option<String>,
+
+    /// Host name or IP address of the server.
+    pub host: Option<String>,
+
+    /// Port of the server.
+    pub port: Option<u16>,
+
+    /// Name of the SQL Server instance. The port of the instance is resolved
+    /// using the SQL Server Browser service unless [`Config::port`] is set.
+    pub instance_name: Option<String>,
+
+    /// Name of the database.
+    pub database: Option<String>,
+
+    /// Application name reported to the server.
+    pub application_name: Option<String>,
+
+    /// Authentication method. See [`Authentication`].
+    pub authentication: Option<Authentication>,
+
+    /// User used by [`Authentication::SqlServer`] and
+    /// [`Authentication::Windows`].
+    pub user: Option<String>,
+
+    /// Password used by [`Authentication::SqlServer`] and
+    /// [`Authentication::Windows`].
+    pub password: Option<String>,
+
+    /// Token used by [`Authentication::AadToken`].
+    pub token: Option<String>,
+
+    /// Encryption level. See [`Encryption`].
+    pub encryption: Option<Encryption>,
+
+    /// Accept the server certificate without validating it.
+    ///
+    /// **Important:** This makes the connection vulnerable to
+    /// man-in-the-middle attacks and should only be used for testing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trust_cert: bool,
+
+    /// Path to a CA certificate used to validate the server certificate.
+    pub trust_cert_ca: Option<String>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] from the given ADO.NET connection string.
+    #[must_use]
+    pub fn from_connection_string<T: Into<String>>(connection_string: T) -> Self {
+        Self {
+            connection_string: Some(connection_string.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::new(self.get_tiberius_config()?);
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns a [`tiberius::Config`] which can be used to connect to the
+    /// database.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn get_tiberius_config(&self) -> Result<tiberius::Config, ConfigError> {
+        let mut cfg = match &self.connection_string {
+            Some(s) => {
+                tiberius::Config::from_ado_string(s).map_err(ConfigError::ConnectionString)?
+            }
+            None => tiberius::Config::new(),
+        };
+        if let Some(host) = &self.host {
+            cfg.host(host);
+        }
+        if let Some(port) = self.port {
+            cfg.port(port);
+        }
+        if let Some(instance_name) = &self.instance_name {
+            cfg.instance_name(instance_name);
+        }
+        if let Some(database) = &self.database {
+            cfg.database(database);
+        }
+        if let Some(application_name) = &self.application_name {
+            cfg.application_name(application_name);
+        }
+        if let Some(authentication) = self.authentication {
+            cfg.authentication(self.get_auth_method(authentication)?);
+        } else if self.user.is_some() || self.password.is_some() {
+            cfg.authentication(self.get_auth_method(Authentication::SqlServer)?);
+        }
+        if let Some(encryption) = self.encryption {
+            cfg.encryption(encryption.into());
+        }
+        if self.trust_cert {
+            cfg.trust_cert();
+        }
+        if let Some(trust_cert_ca) = &self.trust_cert_ca {
+            cfg.trust_cert_ca(trust_cert_ca);
+        }
+        Ok(cfg)
+    }
+
+    fn get_auth_method(&self, authentication: Authentication) -> Result<AuthMethod, ConfigError> {
+        match authentication {
+            Authentication::SqlServer => {
+                let (user, password) = self.get_credentials(authentication)?;
+                Ok(AuthMethod::sql_server(user, password))
+            }
+            #[cfg(any(all(windows, feature = "winauth"), all(unix, feature = "sspi-rs")))]
+            Authentication::Windows => {
+                let (user, password) = self.get_credentials(authentication)?;
+                Ok(AuthMethod::windows(user, password))
+            }
+            #[cfg(any(
+                all(windows, feature = "winauth"),
+                all(unix, feature = "integrated-auth-gssapi")
+            ))]
+            Authentication::Integrated => Ok(AuthMethod::Integrated),
+            Authentication::AadToken => self
+                .token
+                .as_deref()
+                .map(AuthMethod::aad_token)
+                .ok_or(ConfigError::MissingCredentials(authentication)),
+            #[allow(unreachable_patterns)]
+            _ => Err(ConfigError::UnsupportedAuthentication(authentication)),
+        }
+    }
+
+    fn get_credentials(&self, authentication: Authentication) -> Result<(&str, &str), ConfigError> {
+        match (&self.user, &self.password) {
+            (Some(user), Some(password)) => Ok((user, password)),
+            _ => Err(ConfigError::MissingCredentials(authentication)),
+        }
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Possible methods of how a connection is authenticated.
+///
+/// This is a serializable subset of [`tiberius::AuthMethod`]. The
+/// credentials are taken from the [`Config`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Authentication {
+    /// SQL Server authentication using [`Config::user`] and
+    /// [`Config::password`].
+    #[default]
+    SqlServer,
+
+    /// Windows authentication (NTLM) using [`Config::user`] and
+    /// [`Config::password`].
+    ///
+    /// Requires the `winauth` feature on Windows or the `sspi-rs` feature
+    /// on Unix.
+    Windows,
+
+    /// Authenticate as the currently logged in user (SSPI on Windows,
+    /// Kerberos on Unix).
+    ///
+    /// Requires the `winauth` feature on Windows or the
+    /// `integrated-auth-gssapi` feature on Unix.
+    Integrated,
+
+    /// Azure Active Directory authentication using [`Config::token`].
+    AadToken,
+}
+
+/// Encryption level of the connection.
+///
+/// This mirrors [`tiberius::EncryptionLevel`]. If not configured, tiberius
+/// uses [`Required`](Encryption::Required) when a TLS feature is enabled
+/// and [`NotSupported`](Encryption::NotSupported) otherwise.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Encryption {
+    /// Only encrypt the login procedure.
+    Off,
+
+    /// Encrypt everything if possible.
+    On,
+
+    /// Do not encrypt anything.
+    NotSupported,
+
+    /// Encrypt everything and fail if not possible.
+    Required,
+
+    /// Encrypt everything starting with the TLS handshake before the
+    /// prelogin (TDS 8.0).
+    Strict,
+}
+
+impl From<Encryption> for EncryptionLevel {
+    fn from(encryption: Encryption) -> Self {
+        match encryption {
+            Encryption::Off => Self::Off,
+            Encryption::On => Self::On,
+            Encryption::NotSupported => Self::NotSupported,
+            Encryption::Required => Self::Required,
+            Encryption::Strict => Self::Strict,
+        }
+    }
+}
+
+/// This error is returned if there is something wrong with the SQL Server
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `ConnectionString` variant is not `Copy`
+pub enum ConfigError {
+    /// The [`Config::connection_string`] could not be parsed.
+    ConnectionString(tiberius::error::Error),
+
+    /// The [`Authentication`] method is not available with the enabled
+    /// features on this platform.
+    UnsupportedAuthentication(Authentication),
+
+    /// The credentials required by the [`Authentication`] method are not
+    /// configured.
+    MissingCredentials(Authentication),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConnectionString(e) => write!(f, "Invalid connection string: {e}"),
+            Self::UnsupportedAuthentication(a) => {
+                write!(f, "Authentication method {a:?} is not supported")
+            }
+            Self::MissingCredentials(a) => {
+                write!(f, "Missing credentials for authentication method {a:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ConnectionString(e) => Some(e),
+            _ => None,
+        }
+    }
+}
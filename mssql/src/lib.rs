@@ -0,0 +1,97 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    rustdoc::private_intra_doc_links
+)]
+#![forbid(non_ascii_idents, unsafe_code)]
+#![warn(
+    deprecated_in_future,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    unreachable_pub,
+    unused_import_braces,
+    unused_labels,
+    unused_lifetimes,
+    unused_qualifications,
+    unused_results
+)]
+
+mod config;
+
+use deadpool::managed;
+use tiberius::{error::Error, SqlBrowser};
+use tokio::net::TcpStream;
+use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
+
+pub use tiberius;
+
+pub use self::config::{Authentication, Config, ConfigError, Encryption};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "tiberius",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+/// Type of the wrapped [`tiberius::Client`].
+pub type Client = tiberius::Client<Compat<TcpStream>>;
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling [`tiberius::Client`]s.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    config: tiberius::Config,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`tiberius::Config`].
+    #[must_use]
+    pub fn new(config: tiberius::Config) -> Self {
+        Self { config }
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Client;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Client, Error> {
+        match connect(self.config.clone()).await {
+            // Azure SQL redirects clients to the node actually serving the
+            // database.
+            Err(Error::Routing { host, port }) => {
+                let mut config = self.config.clone();
+                config.host(host);
+                config.port(port);
+                connect(config).await
+            }
+            result => result,
+        }
+    }
+
+    async fn recycle(&self, client: &mut Client, _: &Metrics) -> RecycleResult {
+        // There is no statement handle cache to maintain here as tiberius
+        // doesn't expose prepared statement handles. See the "Prepared
+        // statements" section of the README.
+        let _ = client.simple_query("SELECT 1").await?.into_row().await?;
+        Ok(())
+    }
+}
+
+async fn connect(config: tiberius::Config) -> Result<Client, Error> {
+    // Resolves the port via the SQL Server Browser service if an instance
+    // name is configured.
+    let tcp = TcpStream::connect_named(&config).await?;
+    tcp.set_nodelay(true)?;
+    tiberius::Client::connect(config, tcp.compat_write()).await
+}
@@ -0,0 +1,86 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::fmt;
+
+use deadpool::managed;
+use mysql_async::{prelude::Queryable, Conn, Opts};
+
+pub use mysql_async;
+
+pub use self::config::{Config, ConfigError, ManagerConfig, RecyclingMethod};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "mysql_async",
+    Manager,
+    managed::Object<Manager>,
+    mysql_async::Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<mysql_async::Error>;
+
+/// [`Manager`] for creating and recycling [`mysql_async::Conn`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    opts: Opts,
+    config: ManagerConfig,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Opts`] and the default
+    /// [`ManagerConfig`].
+    #[must_use]
+    pub fn new(opts: impl Into<Opts>) -> Self {
+        Self::from_config(opts, ManagerConfig::default())
+    }
+
+    /// Creates a new [`Manager`] using the given [`Opts`] and
+    /// [`ManagerConfig`].
+    #[must_use]
+    pub fn from_config(opts: impl Into<Opts>, config: ManagerConfig) -> Self {
+        Self {
+            opts: opts.into(),
+            config,
+        }
+    }
+}
+
+// Implemented manually to not leak the password of the `Opts`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("host", &self.opts.ip_or_hostname())
+            .field("port", &self.opts.tcp_port())
+            .field("user", &self.opts.user())
+            .field("db", &self.opts.db_name())
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Conn;
+    type Error = mysql_async::Error;
+
+    async fn create(&self) -> Result<Conn, mysql_async::Error> {
+        Conn::new(self.opts.clone()).await
+    }
+
+    async fn recycle(&self, conn: &mut Conn, _: &Metrics) -> RecycleResult {
+        match self.config.recycling_method {
+            RecyclingMethod::Ping => conn.ping().await?,
+            RecyclingMethod::Reset => {
+                // `COM_RESET_CONNECTION` is not supported by older servers
+                // in which case the connection is at least verified.
+                if !conn.reset().await? {
+                    conn.ping().await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
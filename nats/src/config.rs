@@ -0,0 +1,269 @@
+use std::{fmt, io, path::PathBuf, time::Duration};
+
+use async_nats::ConnectOptions;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// NATS__SERVERS=nats://nats1:4222,nats://nats2:4222
+/// NATS__CREDENTIALS_FILE=/etc/nats/deadpool.creds
+/// NATS__TLS_REQUIRED=true
+/// NATS__POOL__MAX_SIZE=4
+/// NATS__POOL__TIMEOUTS__WAIT__SECS=5
+/// NATS__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     nats: deadpool_nats::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("nats.servers")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URLs of the NATS servers, e.g. `nats://localhost:4222` or
+    /// `tls://nats.example.com:4222`.
+    pub servers: Vec<String>,
+
+    /// Name of the client reported to the server.
+    pub name: Option<String>,
+
+    /// User used for authentication.
+    pub user: Option<String>,
+
+    /// Password used for authentication.
+    pub password: Option<String>,
+
+    /// Token used for authentication.
+    pub token: Option<String>,
+
+    /// NKey seed used for authentication.
+    pub nkey: Option<String>,
+
+    /// Credentials file (JWT and NKey seed) used for authentication.
+    pub credentials_file: Option<PathBuf>,
+
+    /// Require a TLS connection.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tls_required: bool,
+
+    /// Start the TLS handshake before receiving the `INFO` of the server.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tls_first: bool,
+
+    /// PEM files containing additional CA certificates used to verify the
+    /// servers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub root_certificates: Vec<PathBuf>,
+
+    /// PEM file containing the client certificate.
+    pub client_cert: Option<PathBuf>,
+
+    /// PEM file containing the key of [`Config::client_cert`].
+    pub client_key: Option<PathBuf>,
+
+    /// Timeout for establishing a connection.
+    pub connection_timeout: Option<Duration>,
+
+    /// Timeout for requests.
+    pub request_timeout: Option<Duration>,
+
+    /// Maximum number of reconnect attempts after the connection was lost.
+    /// A client which gave up is replaced when it is recycled.
+    ///
+    /// Defaults to no limit.
+    pub max_reconnects: Option<usize>,
+
+    /// JetStream domain used by [`ClientWrapper::jetstream()`].
+    ///
+    /// [`ClientWrapper::jetstream()`]: crate::ClientWrapper::jetstream
+    #[cfg(feature = "jetstream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jetstream")))]
+    pub jetstream_domain: Option<String>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given NATS servers.
+    #[must_use]
+    pub fn from_servers<I, S>(servers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            servers: servers.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+        if self.servers.is_empty() {
+            return Err(ConfigError::NoServers);
+        }
+        if self.user.is_some() != self.password.is_some() {
+            return Err(ConfigError::IncompleteCredentials);
+        }
+        let methods = [
+            self.user.is_some(),
+            self.token.is_some(),
+            self.nkey.is_some(),
+            self.credentials_file.is_some(),
+        ];
+        if methods.into_iter().filter(|&m| m).count() > 1 {
+            return Err(ConfigError::MultipleAuthMethods);
+        }
+        if self.client_cert.is_some() != self.client_key.is_some() {
+            return Err(ConfigError::IncompleteClientCertificate);
+        }
+        Ok(())
+    }
+
+    /// Returns [`ConnectOptions`] which can be used to connect to the
+    /// servers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the [`Config::credentials_file`] can't be read.
+    pub async fn get_connect_options(&self) -> io::Result<ConnectOptions> {
+        let mut options = match &self.credentials_file {
+            Some(path) => ConnectOptions::with_credentials_file(path).await?,
+            None => ConnectOptions::new(),
+        };
+        if let (Some(user), Some(password)) = (&self.user, &self.password) {
+            options = options.user_and_password(user.clone(), password.clone());
+        }
+        if let Some(token) = &self.token {
+            options = options.token(token.clone());
+        }
+        if let Some(nkey) = &self.nkey {
+            options = options.nkey(nkey.clone());
+        }
+        if let Some(name) = &self.name {
+            options = options.name(name);
+        }
+        options = options.require_tls(self.tls_required);
+        if self.tls_first {
+            options = options.tls_first();
+        }
+        for path in &self.root_certificates {
+            options = options.add_root_certificates(path.clone());
+        }
+        if let (Some(cert), Some(key)) = (&self.client_cert, &self.client_key) {
+            options = options.add_client_certificate(cert.clone(), key.clone());
+        }
+        if let Some(connection_timeout) = self.connection_timeout {
+            options = options.connection_timeout(connection_timeout);
+        }
+        if let Some(request_timeout) = self.request_timeout {
+            options = options.request_timeout(Some(request_timeout));
+        }
+        if let Some(max_reconnects) = self.max_reconnects {
+            options = options.max_reconnects(max_reconnects);
+        }
+        Ok(options)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the NATS
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `InvalidServer` variant is not `Copy`
+pub enum ConfigError {
+    /// No server was configured.
+    NoServers,
+
+    /// One of the [`Config::servers`] is not a valid server URL.
+    InvalidServer(io::Error),
+
+    /// Only one of [`Config::user`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// More than one authentication method was configured.
+    MultipleAuthMethods,
+
+    /// Only one of [`Config::client_cert`] and [`Config::client_key`] was
+    /// set.
+    IncompleteClientCertificate,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoServers => write!(f, "No server configured"),
+            Self::InvalidServer(e) => write!(f, "Invalid server URL: {e}"),
+            Self::IncompleteCredentials => {
+                write!(f, "User and password must be configured together")
+            }
+            Self::MultipleAuthMethods => write!(
+                f,
+                "Only one of user/password, token, nkey and credentials file can be configured"
+            ),
+            Self::IncompleteClientCertificate => {
+                write!(f, "Client certificate and key must be configured together")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidServer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
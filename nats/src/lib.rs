@@ -0,0 +1,141 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::{fmt, ops::Deref};
+
+use async_nats::{connection::State, Client, ConnectError, ServerAddr};
+use deadpool::managed::{self, RecycleError};
+
+pub use async_nats;
+
+pub use self::config::{Config, ConfigError};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "async-nats",
+    Manager,
+    managed::Object<Manager>,
+    ConnectError,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<ConnectError>;
+
+/// [`Manager`] for creating and recycling [`async_nats::Client`]s.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    servers: Vec<ServerAddr>,
+    config: Config,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let servers = config
+            .servers
+            .iter()
+            .map(|server| server.parse().map_err(ConfigError::InvalidServer))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            servers,
+            config: config.clone(),
+        })
+    }
+}
+
+// Implemented manually to not leak the password, token and nkey. Only the
+// hosts and ports of the servers are printed as their URLs may contain
+// credentials, too.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let servers: Vec<_> = self
+            .servers
+            .iter()
+            .map(|server| format!("{}:{}", server.host(), server.port()))
+            .collect();
+        let mut f = f.debug_struct("Manager");
+        let _ = f
+            .field("servers", &servers)
+            .field("name", &self.config.name)
+            .field("user", &self.config.user)
+            .field("credentials_file", &self.config.credentials_file)
+            .field("tls_required", &self.config.tls_required)
+            .field("tls_first", &self.config.tls_first)
+            .field("connection_timeout", &self.config.connection_timeout)
+            .field("request_timeout", &self.config.request_timeout)
+            .field("max_reconnects", &self.config.max_reconnects);
+        #[cfg(feature = "jetstream")]
+        let _ = f.field("jetstream_domain", &self.config.jetstream_domain);
+        f.finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = ClientWrapper;
+    type Error = ConnectError;
+
+    async fn create(&self) -> Result<ClientWrapper, ConnectError> {
+        let client = self
+            .config
+            .get_connect_options()
+            .await?
+            .connect(&self.servers)
+            .await?;
+        Ok(ClientWrapper {
+            #[cfg(feature = "jetstream")]
+            jetstream: match &self.config.jetstream_domain {
+                Some(domain) => async_nats::jetstream::with_domain(client.clone(), domain),
+                None => async_nats::jetstream::new(client.clone()),
+            },
+            client,
+        })
+    }
+
+    async fn recycle(&self, client: &mut ClientWrapper, _: &Metrics) -> RecycleResult {
+        // The client reconnects on its own. Handing out a client which is
+        // reconnecting would make the caller wait for an unknown amount of
+        // time, so it is replaced by a new one instead.
+        match client.connection_state() {
+            State::Connected => Ok(()),
+            State::Pending => Err(RecycleError::message("Client is reconnecting")),
+            State::Disconnected => Err(RecycleError::message("Client is disconnected")),
+        }
+    }
+}
+
+/// Wrapper around [`async_nats::Client`] which also provides a JetStream
+/// context.
+#[derive(Debug)]
+pub struct ClientWrapper {
+    client: Client,
+    #[cfg(feature = "jetstream")]
+    jetstream: async_nats::jetstream::Context,
+}
+
+#[cfg(feature = "jetstream")]
+impl ClientWrapper {
+    /// Returns the JetStream [`Context`] using this client.
+    ///
+    /// [`Context`]: async_nats::jetstream::Context
+    #[cfg_attr(docsrs, doc(cfg(feature = "jetstream")))]
+    #[must_use]
+    pub fn jetstream(&self) -> &async_nats::jetstream::Context {
+        &self.jetstream
+    }
+}
+
+impl Deref for ClientWrapper {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+
+/// Default port of the Bolt protocol.
+pub const DEFAULT_PORT: u16 = 7687;
+
+/// Host and port of a Neo4j server.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Address {
+    host: String,
+    port: u16,
+}
+
+impl Address {
+    /// Creates a new [`Address`].
+    #[must_use]
+    pub fn new<T: Into<String>>(host: T, port: u16) -> Self {
+        Self {
+            host: host.into(),
+            port,
+        }
+    }
+
+    /// Parses an address of the form `host:port` as used in routing
+    /// tables. IPv6 hosts are enclosed in brackets.
+    ///
+    /// Returns [`None`] if the port is missing or invalid.
+    #[must_use]
+    pub fn parse(address: &str) -> Option<Self> {
+        let (host, port) = address.rsplit_once(':')?;
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self::new(host, port.parse().ok()?))
+    }
+
+    /// Returns the host of this [`Address`].
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the port of this [`Address`].
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "[{}]:{}", self.host, self.port)
+        } else {
+            write!(f, "{}:{}", self.host, self.port)
+        }
+    }
+}
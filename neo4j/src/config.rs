@@ -0,0 +1,248 @@
+use std::{fmt, time::Duration};
+
+use url::Url;
+
+#[cfg(feature = "tls")]
+use crate::TlsConfig;
+use crate::{
+    Address, Cluster, CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime,
+    DEFAULT_PORT,
+};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// NEO4J__URI=neo4j+s://graph.example.com
+/// NEO4J__USER=neo4j
+/// NEO4J__PASSWORD=topsecret
+/// NEO4J__DATABASE=movies
+/// NEO4J__POOL__MAX_SIZE=16
+/// NEO4J__POOL__TIMEOUTS__WAIT__SECS=5
+/// NEO4J__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     neo4j: deadpool_neo4j::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URI of the server, e.g. `bolt://localhost:7687`.
+    ///
+    /// `bolt` URIs connect to a single server while `neo4j` URIs enable
+    /// routing when used with [`Config::create_cluster()`]. The `+s`
+    /// variants of both schemes encrypt the connections using TLS.
+    pub uri: Option<String>,
+
+    /// User for basic authentication.
+    pub user: Option<String>,
+
+    /// Password for basic authentication.
+    pub password: Option<String>,
+
+    /// Database the routing table of a [`Cluster`] is fetched for.
+    ///
+    /// Defaults to the default database of the server. Queries need to
+    /// select the database themselves using the `db` metadata entry.
+    pub database: Option<String>,
+
+    /// User agent sent to the server.
+    ///
+    /// Defaults to `deadpool-neo4j/<version>`.
+    pub user_agent: Option<String>,
+
+    /// Timeout for establishing a connection including the Bolt handshake
+    /// and authentication.
+    pub connect_timeout: Option<Duration>,
+
+    /// TLS configuration used for `bolt+s` and `neo4j+s` URIs.
+    ///
+    /// The Mozilla root certificates are used if this is not set.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration.
+    ///
+    /// When creating a [`Cluster`] this configuration is used for every
+    /// server.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `uri`.
+    #[must_use]
+    pub fn from_uri<T: Into<String>>(uri: T) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] for the server of the [`Config::uri`] using
+    /// this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] for the server of the [`Config::uri`]
+    /// using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let target = self.get_target()?;
+        self.address_builder(&target, target.address.clone())
+    }
+
+    /// Creates a new [`Cluster`] which routes connections to the servers
+    /// of the routing table.
+    ///
+    /// A `bolt` URI creates a [`Cluster`] for a single server without
+    /// routing.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn create_cluster(&self, runtime: Option<Runtime>) -> Result<Cluster, ConfigError> {
+        let target = self.get_target()?;
+        // Checks the remaining configuration before the first use.
+        let _ = Manager::for_address(self, &target, target.address.clone())?;
+        Ok(Cluster::new(self.clone(), target, runtime))
+    }
+
+    pub(crate) fn address_builder(
+        &self,
+        target: &Target,
+        address: Address,
+    ) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::for_address(self, target, address)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    pub(crate) fn get_target(&self) -> Result<Target, ConfigError> {
+        let uri = self.uri.as_deref().ok_or(ConfigError::MissingUri)?;
+        let uri = Url::parse(uri).map_err(ConfigError::InvalidUri)?;
+        let (encrypted, routing) = match uri.scheme() {
+            "bolt" => (false, false),
+            "bolt+s" => (true, false),
+            "neo4j" => (false, true),
+            "neo4j+s" => (true, true),
+            scheme => return Err(ConfigError::UnsupportedScheme(scheme.to_owned())),
+        };
+        #[cfg(not(feature = "tls"))]
+        if encrypted {
+            return Err(ConfigError::TlsNotSupported);
+        }
+        let host = uri
+            .host_str()
+            .ok_or(ConfigError::InvalidUri(url::ParseError::EmptyHost))?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        Ok(Target {
+            address: Address::new(host, uri.port().unwrap_or(DEFAULT_PORT)),
+            #[cfg(feature = "tls")]
+            encrypted,
+            routing,
+        })
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Server and scheme of the [`Config::uri`].
+#[derive(Clone, Debug)]
+pub(crate) struct Target {
+    pub(crate) address: Address,
+    #[cfg(feature = "tls")]
+    pub(crate) encrypted: bool,
+    pub(crate) routing: bool,
+}
+
+/// This error is returned if there is something wrong with the Neo4j
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `UnsupportedScheme` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::uri`] was specified.
+    MissingUri,
+
+    /// The [`Config::uri`] could not be parsed or has no host.
+    InvalidUri(url::ParseError),
+
+    /// The scheme of the [`Config::uri`] is neither `bolt`, `bolt+s`,
+    /// `neo4j` nor `neo4j+s`.
+    UnsupportedScheme(String),
+
+    /// Only one of [`Config::user`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// An encrypted URI was used without enabling the `tls` feature.
+    #[cfg(not(feature = "tls"))]
+    TlsNotSupported,
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUri => write!(f, "No URI specified"),
+            Self::InvalidUri(e) => write!(f, "Invalid URI: {e}"),
+            Self::UnsupportedScheme(scheme) => write!(
+                f,
+                "Unsupported URI scheme `{scheme}`, expected bolt, bolt+s, neo4j or neo4j+s"
+            ),
+            Self::IncompleteCredentials => {
+                write!(f, "User and password must be configured together")
+            }
+            #[cfg(not(feature = "tls"))]
+            Self::TlsNotSupported => write!(f, "Encrypted URIs require the `tls` feature"),
+            #[cfg(feature = "tls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUri(e) => Some(e),
+            #[cfg(feature = "tls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::{fmt, io};
+
+use bolt_client::{
+    bolt_proto::{message::Failure, Message, Value},
+    error::{CommunicationError, ConnectionError},
+};
+
+/// Possible errors returned by the [`Manager`](crate::Manager) and when
+/// fetching routing tables.
+#[derive(Debug)]
+pub enum Error {
+    /// Establishing the TCP or TLS connection failed or timed out.
+    Connect(io::Error),
+
+    /// The Bolt handshake failed, e.g. because the server doesn't support
+    /// any of the offered protocol versions.
+    Handshake(ConnectionError),
+
+    /// Sending a message or reading the response failed.
+    Communication(Box<CommunicationError>),
+
+    /// The server answered with a `FAILURE` message, e.g. because the
+    /// credentials were rejected.
+    Failure {
+        /// Neo4j status code, e.g. `Neo.ClientError.Security.Unauthorized`.
+        code: String,
+
+        /// Description of the failure.
+        message: String,
+    },
+
+    /// The server answered with an unexpected message.
+    UnexpectedResponse(Box<Message>),
+
+    /// The routing table returned by the server is malformed.
+    InvalidRoutingTable,
+}
+
+impl Error {
+    /// Returns the error for a response which isn't a `SUCCESS` message.
+    pub(crate) fn from_response(response: Message) -> Self {
+        match response {
+            Message::Failure(failure) => Self::from_failure(&failure),
+            response => Self::UnexpectedResponse(Box::new(response)),
+        }
+    }
+
+    fn from_failure(failure: &Failure) -> Self {
+        let field = |name: &str| match failure.metadata().get(name) {
+            Some(Value::String(value)) => value.clone(),
+            _ => String::new(),
+        };
+        Self::Failure {
+            code: field("code"),
+            message: field("message"),
+        }
+    }
+}
+
+impl From<ConnectionError> for Error {
+    fn from(e: ConnectionError) -> Self {
+        Self::Handshake(e)
+    }
+}
+
+impl From<CommunicationError> for Error {
+    fn from(e: CommunicationError) -> Self {
+        Self::Communication(Box::new(e))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connect(e) => write!(f, "Failed to connect: {e}"),
+            Self::Handshake(e) => write!(f, "Bolt handshake failed: {e}"),
+            Self::Communication(e) => write!(f, "Bolt communication failed: {e}"),
+            Self::Failure { code, message } => write!(f, "{code}: {message}"),
+            Self::UnexpectedResponse(message) => {
+                write!(f, "Unexpected response: {message:?}")
+            }
+            Self::InvalidRoutingTable => write!(f, "Invalid routing table"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Connect(e) => Some(e),
+            Self::Handshake(e) => Some(e),
+            Self::Communication(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod address;
+mod config;
+mod error;
+mod routing;
+mod stream;
+#[cfg(feature = "tls")]
+mod tls;
+
+use std::{
+    collections::HashMap,
+    fmt, io,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use bolt_client::{
+    bolt_proto::{
+        version::{V4_1, V4_2, V4_3, V4_4},
+        Message, ServerState, Value,
+    },
+    Metadata,
+};
+use deadpool::managed::{self, RecycleError};
+use tokio::{net::TcpStream, time::timeout};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+
+pub use bolt_client;
+
+use self::config::Target;
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub use self::tls::TlsConfig;
+pub use self::{
+    address::{Address, DEFAULT_PORT},
+    config::{Config, ConfigError},
+    error::Error,
+    routing::{AccessMode, Cluster, ClusterError, RoutingTable},
+    stream::Stream,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "bolt-client",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Bolt protocol versions offered during the handshake in order of
+/// preference.
+const VERSIONS: [u32; 4] = [V4_4, V4_3, V4_2, V4_1];
+
+/// [`bolt_client::Client`] using a [`Stream`].
+pub type Client = bolt_client::Client<Compat<Stream>>;
+
+/// [`Manager`] for creating and recycling Bolt [`Connection`]s to a single
+/// server.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    address: Address,
+    hello: HashMap<String, Value>,
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "tls")]
+    tls: Option<tokio_rustls::TlsConnector>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the server of the [`Config::uri`]
+    /// using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let target = config.get_target()?;
+        Self::for_address(config, &target, target.address.clone())
+    }
+
+    /// Creates a new [`Manager`] for the given `address` which was either
+    /// taken from the [`Config::uri`] or from a routing table.
+    pub(crate) fn for_address(
+        config: &Config,
+        target: &Target,
+        address: Address,
+    ) -> Result<Self, ConfigError> {
+        let user_agent = config
+            .user_agent
+            .clone()
+            .unwrap_or_else(|| concat!("deadpool-neo4j/", env!("CARGO_PKG_VERSION")).to_owned());
+        let mut hello = HashMap::from([("user_agent".to_owned(), Value::String(user_agent))]);
+        match (&config.user, &config.password) {
+            (Some(user), Some(password)) => {
+                hello.extend([
+                    ("scheme".to_owned(), Value::from("basic")),
+                    ("principal".to_owned(), Value::String(user.clone())),
+                    ("credentials".to_owned(), Value::String(password.clone())),
+                ]);
+            }
+            (None, None) => {
+                let _ = hello.insert("scheme".to_owned(), Value::from("none"));
+            }
+            _ => return Err(ConfigError::IncompleteCredentials),
+        }
+        if target.routing {
+            let context = HashMap::from([(
+                "address".to_owned(),
+                Value::String(target.address.to_string()),
+            )]);
+            let _ = hello.insert("routing".to_owned(), Value::Map(context));
+        }
+        Ok(Self {
+            #[cfg(feature = "tls")]
+            tls: if target.encrypted {
+                let tls = config.tls.clone().unwrap_or_default();
+                Some(tls.connector()?)
+            } else {
+                None
+            },
+            address,
+            hello,
+            connect_timeout: config.connect_timeout,
+        })
+    }
+
+    /// Returns the [`Address`] of the server.
+    #[must_use]
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    async fn connect_stream(&self) -> Result<Stream, Error> {
+        let stream = TcpStream::connect((self.address.host(), self.address.port()))
+            .await
+            .map_err(Error::Connect)?;
+        #[cfg(feature = "tls")]
+        if let Some(connector) = &self.tls {
+            use tokio_rustls::rustls::pki_types::ServerName;
+
+            let server_name = ServerName::try_from(self.address.host().to_owned())
+                .map_err(|e| Error::Connect(io::Error::new(io::ErrorKind::InvalidInput, e)))?;
+            let stream = connector
+                .connect(server_name, stream)
+                .await
+                .map_err(Error::Connect)?;
+            return Ok(Stream::Tls(Box::new(stream)));
+        }
+        Ok(Stream::Tcp(stream))
+    }
+
+    async fn connect(&self) -> Result<Client, Error> {
+        let stream = self.connect_stream().await?;
+        let mut client = Client::new(stream.compat(), &VERSIONS).await?;
+        let metadata = Metadata::from(self.hello.clone());
+        match client.hello(metadata).await? {
+            Message::Success(_) => Ok(client),
+            response => Err(Error::from_response(response)),
+        }
+    }
+}
+
+// Implemented manually to not leak the credentials.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("address", &self.address)
+            .field("user_agent", &self.hello.get("user_agent"))
+            .field("connect_timeout", &self.connect_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        let client = match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, self.connect())
+                .await
+                .map_err(|_| Error::Connect(io::ErrorKind::TimedOut.into()))??,
+            None => self.connect().await?,
+        };
+        Ok(Connection {
+            client,
+            address: self.address.clone(),
+        })
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        if conn.server_state() == ServerState::Defunct {
+            return Err(RecycleError::message("Connection is defunct"));
+        }
+        // Discards open results and transactions and clears failures so the
+        // next user starts in the `READY` state.
+        match conn.reset().await.map_err(Error::from)? {
+            Message::Success(_) => Ok(()),
+            response => Err(Error::from_response(response).into()),
+        }
+    }
+}
+
+/// Bolt connection to a single server.
+#[derive(Debug)]
+pub struct Connection {
+    client: Client,
+    address: Address,
+}
+
+impl Connection {
+    /// Returns the [`Address`] of the server.
+    #[must_use]
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+}
+
+impl Deref for Connection {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+}
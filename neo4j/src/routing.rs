@@ -0,0 +1,526 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+use bolt_client::{
+    bolt_proto::{Message, Value},
+    Metadata, RoutingContext,
+};
+
+use crate::{
+    config::Target, Address, Config, Connection, CreatePoolError, Error, Object, Pool, PoolError,
+    Runtime, TimeoutType,
+};
+
+/// Kind of work a connection retrieved from a [`Cluster`] is used for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessMode {
+    /// Read work which any server with the `READ` role can handle.
+    Read,
+
+    /// Write work which needs a server with the `WRITE` role.
+    Write,
+}
+
+impl fmt::Display for AccessMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// Servers of a Neo4j cluster by role as returned by the `ROUTE` message.
+#[derive(Clone, Debug)]
+pub struct RoutingTable {
+    routers: Vec<Address>,
+    readers: Vec<Address>,
+    writers: Vec<Address>,
+    expires_at: Option<Instant>,
+}
+
+impl RoutingTable {
+    /// Creates a [`RoutingTable`] which never expires and uses the given
+    /// server for every role.
+    fn direct(address: Address) -> Self {
+        Self {
+            routers: vec![address.clone()],
+            readers: vec![address.clone()],
+            writers: vec![address],
+            expires_at: None,
+        }
+    }
+
+    /// Parses the `rt` entry of the `SUCCESS` metadata of a `ROUTE`
+    /// message.
+    fn from_metadata(metadata: &HashMap<String, Value>) -> Option<Self> {
+        let Some(Value::Map(rt)) = metadata.get("rt") else {
+            return None;
+        };
+        let Some(Value::Integer(ttl)) = rt.get("ttl") else {
+            return None;
+        };
+        let Some(Value::List(servers)) = rt.get("servers") else {
+            return None;
+        };
+        let mut table = Self {
+            routers: Vec::new(),
+            readers: Vec::new(),
+            writers: Vec::new(),
+            expires_at: Some(Instant::now() + Duration::from_secs(u64::try_from(*ttl).ok()?)),
+        };
+        for server in servers {
+            let Value::Map(server) = server else {
+                return None;
+            };
+            let (Some(Value::String(role)), Some(Value::List(addresses))) =
+                (server.get("role"), server.get("addresses"))
+            else {
+                return None;
+            };
+            let addresses = addresses.iter().map(|address| match address {
+                Value::String(address) => Address::parse(address),
+                _ => None,
+            });
+            let addresses = addresses.collect::<Option<Vec<_>>>()?;
+            match role.as_str() {
+                "ROUTE" => table.routers.extend(addresses),
+                "READ" => table.readers.extend(addresses),
+                "WRITE" => table.writers.extend(addresses),
+                _ => {}
+            }
+        }
+        // A table without routers can never be refreshed.
+        if table.routers.is_empty() {
+            return None;
+        }
+        Some(table)
+    }
+
+    /// Returns the servers which can fetch routing tables.
+    #[must_use]
+    pub fn routers(&self) -> &[Address] {
+        &self.routers
+    }
+
+    /// Returns the servers handling read work.
+    #[must_use]
+    pub fn readers(&self) -> &[Address] {
+        &self.readers
+    }
+
+    /// Returns the servers handling write work.
+    ///
+    /// This is empty while the cluster has no leader.
+    #[must_use]
+    pub fn writers(&self) -> &[Address] {
+        &self.writers
+    }
+
+    /// Returns the servers handling work of the given [`AccessMode`].
+    #[must_use]
+    pub fn servers(&self, mode: AccessMode) -> &[Address] {
+        match mode {
+            AccessMode::Read => &self.readers,
+            AccessMode::Write => &self.writers,
+        }
+    }
+
+    /// Returns whether the time to live of this [`RoutingTable`] has
+    /// passed.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Instant::now())
+    }
+
+    fn contains(&self, address: &Address) -> bool {
+        self.routers.contains(address)
+            || self.readers.contains(address)
+            || self.writers.contains(address)
+    }
+}
+
+/// Servers of a Neo4j cluster with one [`Pool`] per server.
+///
+/// The routing table is fetched using the `ROUTE` message, which requires
+/// Bolt 4.3 or newer, when it is first needed and whenever its time to
+/// live has passed. Connections are distributed across the servers of the
+/// requested [`AccessMode`] in a round-robin fashion. Pools of servers
+/// which are no longer part of the routing table are closed, except the one
+/// of the server of the [`Config::uri`].
+///
+/// A [`Cluster`] created for a `bolt` URI doesn't fetch routing tables and
+/// uses the server of the URI for everything.
+#[derive(Clone, Debug)]
+pub struct Cluster {
+    inner: Arc<ClusterInner>,
+}
+
+struct ClusterInner {
+    config: Config,
+    target: Target,
+    runtime: Option<Runtime>,
+    table: RwLock<Option<RoutingTable>>,
+    pools: RwLock<HashMap<Address, Pool>>,
+    next: AtomicUsize,
+}
+
+// Implemented manually to not leak the credentials of the config.
+impl fmt::Debug for ClusterInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClusterInner")
+            .field("target", &self.target)
+            .field("runtime", &self.runtime)
+            .field("table", &self.table)
+            .field("pools", &self.pools)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Cluster {
+    pub(crate) fn new(config: Config, target: Target, runtime: Option<Runtime>) -> Self {
+        let table = (!target.routing).then(|| RoutingTable::direct(target.address.clone()));
+        Self {
+            inner: Arc::new(ClusterInner {
+                config,
+                target,
+                runtime,
+                table: RwLock::new(table),
+                pools: RwLock::new(HashMap::new()),
+                next: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the next server
+    /// handling work of the given [`AccessMode`].
+    ///
+    /// The routing table is refreshed on next use if the server could not
+    /// be connected to. Other errors, e.g. timeouts while waiting for a
+    /// free connection of a busy server, don't affect the routing table.
+    ///
+    /// # Errors
+    ///
+    /// See [`ClusterError`] for details.
+    pub async fn get(&self, mode: AccessMode) -> Result<Object, ClusterError> {
+        let table = self.routing_table().await?;
+        let servers = table.servers(mode);
+        if servers.is_empty() {
+            self.invalidate();
+            return Err(ClusterError::NoServers(mode));
+        }
+        let next = self.inner.next.fetch_add(1, Ordering::Relaxed);
+        let pool = self.pool(&servers[next % servers.len()])?;
+        pool.get().await.map_err(|e| {
+            if is_connect_error(&e) {
+                self.invalidate();
+            }
+            ClusterError::Pool(e)
+        })
+    }
+
+    /// Returns the current [`RoutingTable`] and fetches a new one if it
+    /// expired.
+    ///
+    /// The known routers are asked first. The server of the
+    /// [`Config::uri`] is used if none of them answers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ClusterError`] of the server of the
+    /// [`Config::uri`] if no router returned a routing table.
+    pub async fn routing_table(&self) -> Result<RoutingTable, ClusterError> {
+        let routers = match &*self.inner.table.read().unwrap() {
+            Some(table) if !table.is_expired() => return Ok(table.clone()),
+            Some(table) => table.routers.clone(),
+            None => Vec::new(),
+        };
+        let initial = &self.inner.target.address;
+        for router in routers.iter().filter(|router| *router != initial) {
+            if let Ok(table) = self.fetch_routing_table(router).await {
+                self.update(table.clone());
+                return Ok(table);
+            }
+        }
+        let table = self.fetch_routing_table(initial).await?;
+        self.update(table.clone());
+        Ok(table)
+    }
+
+    /// Marks the current [`RoutingTable`] as expired so it is fetched
+    /// again on next use.
+    pub fn invalidate(&self) {
+        if !self.inner.target.routing {
+            return;
+        }
+        if let Some(table) = &mut *self.inner.table.write().unwrap() {
+            table.expires_at = Some(Instant::now());
+        }
+    }
+
+    /// Returns the [`Pool`] for the given server and creates it if
+    /// necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterError::Create`] if the [`Pool`] could not be
+    /// created.
+    pub fn pool(&self, address: &Address) -> Result<Pool, ClusterError> {
+        if let Some(pool) = self.inner.pools.read().unwrap().get(address) {
+            return Ok(pool.clone());
+        }
+        let mut pools = self.inner.pools.write().unwrap();
+        if let Some(pool) = pools.get(address) {
+            return Ok(pool.clone());
+        }
+        let mut builder = self
+            .inner
+            .config
+            .address_builder(&self.inner.target, address.clone())
+            .map_err(|e| ClusterError::Create(CreatePoolError::Config(e)))?;
+        if let Some(runtime) = self.inner.runtime {
+            builder = builder.runtime(runtime);
+        }
+        let pool = builder
+            .build()
+            .map_err(|e| ClusterError::Create(CreatePoolError::Build(e)))?;
+        let _ = pools.insert(address.clone(), pool.clone());
+        Ok(pool)
+    }
+
+    /// Returns the addresses of all servers which currently have a
+    /// [`Pool`].
+    #[must_use]
+    pub fn addresses(&self) -> Vec<Address> {
+        self.inner.pools.read().unwrap().keys().cloned().collect()
+    }
+
+    async fn fetch_routing_table(&self, router: &Address) -> Result<RoutingTable, ClusterError> {
+        let mut conn = self.pool(router)?.get().await.map_err(ClusterError::Pool)?;
+        fetch(
+            &mut conn,
+            &self.inner.target,
+            self.inner.config.database.as_deref(),
+        )
+        .await
+        .map_err(ClusterError::Routing)
+    }
+
+    /// Stores the given [`RoutingTable`] and closes the pools of servers
+    /// which are no longer part of it.
+    ///
+    /// The pool of the server of the [`Config::uri`] is always kept as it
+    /// is asked for a routing table if none of the routers answers.
+    fn update(&self, table: RoutingTable) {
+        let initial = &self.inner.target.address;
+        self.inner.pools.write().unwrap().retain(|address, pool| {
+            let keep = address == initial || table.contains(address);
+            if !keep {
+                pool.close();
+            }
+            keep
+        });
+        *self.inner.table.write().unwrap() = Some(table);
+    }
+}
+
+/// Returns whether the given [`PoolError`] means that the server could not
+/// be connected to.
+fn is_connect_error(e: &PoolError) -> bool {
+    matches!(
+        e,
+        PoolError::Backend(Error::Connect(_)) | PoolError::Timeout(TimeoutType::Create)
+    )
+}
+
+/// Fetches the routing table of the given `database` using the `ROUTE`
+/// message.
+async fn fetch(
+    conn: &mut Connection,
+    target: &Target,
+    database: Option<&str>,
+) -> Result<RoutingTable, Error> {
+    let context = RoutingContext::from_iter([("address", target.address.to_string())]);
+    let metadata = database.map(|database| Metadata::from_iter([("db", database)]));
+    match conn.route(context, Vec::<String>::new(), metadata).await? {
+        Message::Success(success) => {
+            RoutingTable::from_metadata(success.metadata()).ok_or(Error::InvalidRoutingTable)
+        }
+        response => Err(Error::from_response(response)),
+    }
+}
+
+/// This error is returned when retrieving an [`Object`] from a [`Cluster`]
+/// fails.
+#[derive(Debug)]
+pub enum ClusterError {
+    /// The [`Pool`] of a server could not be created.
+    Create(CreatePoolError),
+
+    /// No connection could be retrieved from the [`Pool`] of a server.
+    Pool(PoolError),
+
+    /// The router answered the `ROUTE` message with an error.
+    Routing(Error),
+
+    /// The routing table contains no server for the [`AccessMode`].
+    NoServers(AccessMode),
+}
+
+impl fmt::Display for ClusterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create(e) => write!(f, "Failed to create pool: {e}"),
+            Self::Pool(e) => write!(f, "Failed to get connection: {e}"),
+            Self::Routing(e) => write!(f, "Failed to fetch routing table: {e}"),
+            Self::NoServers(mode) => write!(f, "No server available for {mode} access"),
+        }
+    }
+}
+
+impl std::error::Error for ClusterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Create(e) => Some(e),
+            Self::Pool(e) => Some(e),
+            Self::Routing(e) => Some(e),
+            Self::NoServers(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    fn map<const N: usize>(entries: [(&str, Value); N]) -> Value {
+        Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    fn server(role: &str, addresses: &[&str]) -> Value {
+        let addresses = addresses
+            .iter()
+            .map(|address| Value::String((*address).to_owned()))
+            .collect();
+        map([
+            ("role", Value::String(role.to_owned())),
+            ("addresses", Value::List(addresses)),
+        ])
+    }
+
+    fn metadata(ttl: i64, servers: Vec<Value>) -> HashMap<String, Value> {
+        HashMap::from([(
+            "rt".to_owned(),
+            map([
+                ("ttl", Value::Integer(ttl)),
+                ("servers", Value::List(servers)),
+            ]),
+        )])
+    }
+
+    #[test]
+    fn only_connect_errors_are_connect_errors() {
+        let refused = io::Error::from(io::ErrorKind::ConnectionRefused);
+        assert!(is_connect_error(&PoolError::Backend(Error::Connect(
+            refused
+        ))));
+        assert!(is_connect_error(&PoolError::Timeout(TimeoutType::Create)));
+        assert!(!is_connect_error(&PoolError::Timeout(TimeoutType::Wait)));
+        assert!(!is_connect_error(&PoolError::Timeout(TimeoutType::Recycle)));
+        assert!(!is_connect_error(&PoolError::Backend(Error::Failure {
+            code: "Neo.ClientError.Security.Unauthorized".to_owned(),
+            message: String::new(),
+        })));
+        assert!(!is_connect_error(&PoolError::Closed));
+    }
+
+    #[test]
+    fn servers_are_grouped_by_role() {
+        let metadata = metadata(
+            300,
+            vec![
+                server("ROUTE", &["core1:7687", "core2:7687"]),
+                server("READ", &["replica:7687", "[::1]:7688"]),
+                server("WRITE", &["core1:7687"]),
+            ],
+        );
+        let table = RoutingTable::from_metadata(&metadata).unwrap();
+        assert_eq!(
+            table.routers(),
+            [Address::new("core1", 7687), Address::new("core2", 7687)]
+        );
+        assert_eq!(
+            table.servers(AccessMode::Read),
+            [Address::new("replica", 7687), Address::new("::1", 7688)]
+        );
+        assert_eq!(
+            table.servers(AccessMode::Write),
+            [Address::new("core1", 7687)]
+        );
+        assert!(!table.is_expired());
+    }
+
+    #[test]
+    fn zero_ttl_expires_immediately() {
+        let metadata = metadata(0, vec![server("ROUTE", &["core1:7687"])]);
+        let table = RoutingTable::from_metadata(&metadata).unwrap();
+        assert!(table.is_expired());
+        assert!(table.writers().is_empty());
+    }
+
+    #[test]
+    fn unknown_roles_are_ignored() {
+        let metadata = metadata(
+            300,
+            vec![
+                server("ROUTE", &["core1:7687"]),
+                server("ARBITER", &["arbiter:7687"]),
+            ],
+        );
+        let table = RoutingTable::from_metadata(&metadata).unwrap();
+        assert!(!table.contains(&Address::new("arbiter", 7687)));
+    }
+
+    #[test]
+    fn table_without_routers_is_rejected() {
+        let metadata = metadata(300, vec![server("READ", &["replica:7687"])]);
+        assert!(RoutingTable::from_metadata(&metadata).is_none());
+    }
+
+    #[test]
+    fn invalid_tables_are_rejected() {
+        let route = || server("ROUTE", &["core1:7687"]);
+        let invalid = [
+            HashMap::new(),
+            metadata(-1, vec![route()]),
+            metadata(300, vec![route(), server("READ", &["replica"])]),
+            metadata(300, vec![route(), Value::String("READ".to_owned())]),
+            metadata(
+                300,
+                vec![route(), map([("role", Value::String("READ".to_owned()))])],
+            ),
+            HashMap::from([("rt".to_owned(), map([("ttl", Value::Integer(300))]))]),
+        ];
+        for metadata in invalid {
+            assert!(
+                RoutingTable::from_metadata(&metadata).is_none(),
+                "{metadata:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{fmt, time::Duration};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// ODBC__DSN=warehouse
+/// ODBC__USER=report
+/// ODBC__PASSWORD=topsecret
+/// ODBC__LOGIN_TIMEOUT__SECS=10
+/// ODBC__LOGIN_TIMEOUT__NANOS=0
+/// ODBC__SETUP_STATEMENTS=SET SCHEMA reporting
+/// ODBC__POOL__MAX_SIZE=8
+/// ODBC__POOL__TIMEOUTS__WAIT__SECS=5
+/// ODBC__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     odbc: deadpool_odbc::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(";")
+///                     .with_list_parse_key("odbc.setup_statements")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Connection string passed to the driver manager, e.g.
+    /// `Driver={PostgreSQL Unicode};Server=localhost;Database=deadpool`.
+    ///
+    /// Takes precedence over [`Config::dsn`].
+    pub connection_string: Option<String>,
+
+    /// Name of a data source configured in the `odbc.ini` of the driver
+    /// manager.
+    pub dsn: Option<String>,
+
+    /// User used to connect to the [`Config::dsn`].
+    pub user: Option<String>,
+
+    /// Password used to connect to the [`Config::dsn`].
+    pub password: Option<String>,
+
+    /// Timeout for the login to the data source.
+    ///
+    /// The timeout is passed to the driver in whole seconds. Defaults to
+    /// the driver default.
+    pub login_timeout: Option<Duration>,
+
+    /// Network packet size in bytes. Not all drivers support this option.
+    pub packet_size: Option<u32>,
+
+    /// Statements executed on every new connection, e.g. to select a schema
+    /// or to set session options.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub setup_statements: Vec<String>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `connection_string`.
+    #[must_use]
+    pub fn from_connection_string<S: Into<String>>(connection_string: S) -> Self {
+        Self {
+            connection_string: Some(connection_string.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Config`] for the given `dsn`.
+    #[must_use]
+    pub fn from_dsn<S: Into<String>>(dsn: S) -> Self {
+        Self {
+            dsn: Some(dsn.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Runtime) -> Result<Pool, CreatePoolError> {
+        self.builder(runtime)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self, runtime: Runtime) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self, runtime)?;
+        Ok(Pool::builder(manager)
+            .config(self.get_pool_config())
+            .runtime(runtime))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the ODBC
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigError {
+    /// Neither [`Config::connection_string`] nor [`Config::dsn`] was
+    /// specified.
+    MissingDataSource,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDataSource => write!(f, "No connection string or DSN specified"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
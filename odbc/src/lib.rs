@@ -0,0 +1,149 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::{fmt, sync::Arc};
+
+use deadpool::managed::{self, RecycleError};
+use deadpool_sync::SyncWrapper;
+use odbc_api::ConnectionOptions;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+pub use odbc_api;
+
+deadpool::managed_reexports!(
+    "odbc-api",
+    Manager,
+    managed::Object<Manager>,
+    odbc_api::Error,
+    ConfigError
+);
+
+pub use self::config::{Config, ConfigError};
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
+
+/// Data source the connections of a [`Manager`] are opened for.
+#[derive(Clone)]
+enum DataSource {
+    ConnectionString(String),
+    Dsn {
+        dsn: String,
+        user: String,
+        password: String,
+    },
+}
+
+/// [`Manager`] for creating and recycling ODBC [`Connection`]s.
+///
+/// All connections are allocated from the process wide ODBC environment
+/// returned by [`odbc_api::environment()`].
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    data_source: DataSource,
+    options: ConnectionOptions,
+    setup_statements: Arc<[String]>,
+    runtime: Runtime,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`] backed by the
+    /// specified [`Runtime`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config, runtime: Runtime) -> Result<Self, ConfigError> {
+        let data_source = match (&config.connection_string, &config.dsn) {
+            (Some(connection_string), _) => DataSource::ConnectionString(connection_string.clone()),
+            (None, Some(dsn)) => DataSource::Dsn {
+                dsn: dsn.clone(),
+                user: config.user.clone().unwrap_or_default(),
+                password: config.password.clone().unwrap_or_default(),
+            },
+            (None, None) => return Err(ConfigError::MissingDataSource),
+        };
+        Ok(Self {
+            data_source,
+            options: ConnectionOptions {
+                login_timeout_sec: config
+                    .login_timeout
+                    .map(|t| t.as_secs().try_into().unwrap_or(u32::MAX)),
+                packet_size: config.packet_size,
+            },
+            setup_statements: config.setup_statements.clone().into(),
+            runtime,
+        })
+    }
+}
+
+// Implemented manually to not leak the password.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("Manager");
+        if let DataSource::Dsn { dsn, user, .. } = &self.data_source {
+            let _ = f.field("dsn", dsn).field("user", user);
+        }
+        f.field("login_timeout_sec", &self.options.login_timeout_sec)
+            .field("packet_size", &self.options.packet_size)
+            .field("setup_statements", &self.setup_statements)
+            .field("runtime", &self.runtime)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = SyncWrapper<odbc_api::Connection<'static>>;
+    type Error = odbc_api::Error;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        let data_source = self.data_source.clone();
+        let options = self.options;
+        let setup_statements = self.setup_statements.clone();
+        SyncWrapper::new(self.runtime, move || {
+            let env = odbc_api::environment()?;
+            let conn = match &data_source {
+                DataSource::ConnectionString(connection_string) => {
+                    env.connect_with_connection_string(connection_string, options)?
+                }
+                DataSource::Dsn {
+                    dsn,
+                    user,
+                    password,
+                } => env.connect(dsn, user, password, options)?,
+            };
+            for statement in setup_statements.iter() {
+                let _ = conn.execute(statement, (), None)?;
+            }
+            Ok(conn)
+        })
+        .await
+    }
+
+    async fn recycle(
+        &self,
+        conn: &mut Self::Type,
+        _: &Metrics,
+    ) -> managed::RecycleResult<Self::Error> {
+        if conn.is_mutex_poisoned() {
+            return Err(RecycleError::Message(
+                "Mutex is poisoned. Connection is considered unusable.".into(),
+            ));
+        }
+        // Asks the driver for `SQL_ATTR_CONNECTION_DEAD` which doesn't
+        // cause a round trip to the server.
+        let is_dead = conn
+            .interact(|conn| conn.is_dead())
+            .await
+            .map_err(|e| RecycleError::message(format!("{e}")))??;
+        if is_dead {
+            Err(RecycleError::message("Connection is dead"))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::{collections::HashMap, fmt, path::PathBuf, time::Duration};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// PROCESS__PROGRAM=python3
+/// PROCESS__ARGS=-u,worker.py
+/// PROCESS__ENV__PYTHONHASHSEED=0
+/// PROCESS__HANDSHAKE__REQUEST=ping
+/// PROCESS__HANDSHAKE__RESPONSE=pong
+/// PROCESS__HANDSHAKE_TIMEOUT__SECS=2
+/// PROCESS__HANDSHAKE_TIMEOUT__NANOS=0
+/// PROCESS__POOL__MAX_SIZE=4
+/// PROCESS__POOL__TIMEOUTS__WAIT__SECS=5
+/// PROCESS__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     process: deadpool_process::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("process.args")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Program executed for every worker process.
+    ///
+    /// The `PATH` is searched if this is not a path.
+    pub program: Option<String>,
+
+    /// Arguments passed to the [`Config::program`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub args: Vec<String>,
+
+    /// Environment variables set in addition to the inherited ones.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub env: HashMap<String, String>,
+
+    /// Working directory of the worker processes. Defaults to the working
+    /// directory of the current process.
+    pub current_dir: Option<PathBuf>,
+
+    /// Handshake performed after spawning a process and before it is
+    /// reused.
+    ///
+    /// Without a handshake a process is only checked for having exited.
+    pub handshake: Option<Handshake>,
+
+    /// Timeout for receiving the [`Handshake::response`]. Defaults to 5
+    /// seconds.
+    pub handshake_timeout: Option<Duration>,
+
+    /// Discards the stderr of the processes instead of inheriting the
+    /// stderr of the current process.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub discard_stderr: bool,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `program`.
+    #[must_use]
+    pub fn new<S: Into<String>>(program: S) -> Self {
+        Self {
+            program: Some(program.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Line based handshake used to check that a worker process is alive and
+/// ready to accept requests.
+///
+/// The [`Handshake::request`] is written as a single line to the stdin of
+/// the process which must answer with the [`Handshake::response`] as a
+/// single line on its stdout.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Handshake {
+    /// Line written to the stdin of the process.
+    pub request: String,
+
+    /// Line the process is expected to answer with.
+    pub response: String,
+}
+
+impl Handshake {
+    /// Creates a new [`Handshake`] sending the given `request` and expecting
+    /// the given `response`.
+    #[must_use]
+    pub fn new<Q: Into<String>, R: Into<String>>(request: Q, response: R) -> Self {
+        Self {
+            request: request.into(),
+            response: response.into(),
+        }
+    }
+}
+
+/// This error is returned if there is something wrong with the process
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigError {
+    /// No [`Config::program`] was specified.
+    MissingProgram,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingProgram => write!(f, "No program specified"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
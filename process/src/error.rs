@@ -0,0 +1,52 @@
+use std::{fmt, io, process::ExitStatus};
+
+/// Possible errors returned by the [`Manager`](crate::Manager) and the
+/// [`Process`](crate::Process).
+#[derive(Debug)]
+pub enum Error {
+    /// Spawning the process or communicating with it failed.
+    Io(io::Error),
+
+    /// The process exited.
+    Exited(ExitStatus),
+
+    /// The process closed its stdout.
+    Eof,
+
+    /// The process answered the [`Handshake`](crate::Handshake) with an
+    /// unexpected line.
+    UnexpectedResponse(String),
+
+    /// The process didn't answer the [`Handshake`](crate::Handshake)
+    /// within the [`Config::handshake_timeout`](crate::Config::handshake_timeout).
+    Timeout,
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Exited(status) => write!(f, "Process exited with {status}"),
+            Self::Eof => write!(f, "Process closed its stdout"),
+            Self::UnexpectedResponse(line) => {
+                write!(f, "Unexpected handshake response `{line}`")
+            }
+            Self::Timeout => write!(f, "Timed out waiting for the handshake response"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,205 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+
+use std::{collections::HashMap, io, path::PathBuf, process::Stdio, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    process::{Child, ChildStdin, ChildStdout, Command},
+    time::timeout,
+};
+
+pub use self::{
+    config::{Config, ConfigError, Handshake},
+    error::Error,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "tokio::process",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Default timeout for receiving the [`Handshake::response`].
+const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// [`Manager`] for spawning and recycling worker [`Process`]es.
+///
+/// Every process is killed as soon as it is dropped, i.e. when it is
+/// evicted from the [`Pool`] or the [`Pool`] itself is dropped.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    program: String,
+    args: Vec<String>,
+    env: HashMap<String, String>,
+    current_dir: Option<PathBuf>,
+    handshake: Option<Handshake>,
+    handshake_timeout: Duration,
+    discard_stderr: bool,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self {
+            program: config.program.clone().ok_or(ConfigError::MissingProgram)?,
+            args: config.args.clone(),
+            env: config.env.clone(),
+            current_dir: config.current_dir.clone(),
+            handshake: config.handshake.clone(),
+            handshake_timeout: config
+                .handshake_timeout
+                .unwrap_or(DEFAULT_HANDSHAKE_TIMEOUT),
+            discard_stderr: config.discard_stderr,
+        })
+    }
+
+    fn command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        let _ = command
+            .args(&self.args)
+            .envs(&self.env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(if self.discard_stderr {
+                Stdio::null()
+            } else {
+                Stdio::inherit()
+            })
+            .kill_on_drop(true);
+        if let Some(current_dir) = &self.current_dir {
+            let _ = command.current_dir(current_dir);
+        }
+        command
+    }
+
+    /// Performs the configured [`Handshake`] with the given `process`.
+    async fn handshake(&self, process: &mut Process) -> Result<(), Error> {
+        let Some(handshake) = &self.handshake else {
+            return Ok(());
+        };
+        let response = timeout(self.handshake_timeout, process.request(&handshake.request))
+            .await
+            .map_err(|_| Error::Timeout)??;
+        if response == handshake.response {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedResponse(response))
+        }
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Process;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Process, Error> {
+        let mut child = self.command().spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(io::Error::other("stdin or stdout of the process is not piped").into());
+        };
+        let mut process = Process {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        };
+        self.handshake(&mut process).await?;
+        Ok(process)
+    }
+
+    async fn recycle(&self, process: &mut Process, _: &Metrics) -> RecycleResult {
+        if let Some(status) = process.child.try_wait().map_err(Error::Io)? {
+            return Err(RecycleError::Backend(Error::Exited(status)));
+        }
+        // An unread line of the previous user makes the handshake fail
+        // which prevents handing out a process in an unknown state.
+        self.handshake(process).await?;
+        Ok(())
+    }
+}
+
+/// Worker process with piped stdin and stdout.
+#[derive(Debug)]
+pub struct Process {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl Process {
+    /// Returns the OS-assigned process identifier.
+    #[must_use]
+    pub fn id(&self) -> Option<u32> {
+        self.child.id()
+    }
+
+    /// Returns the stdin of this [`Process`].
+    pub fn stdin(&mut self) -> &mut ChildStdin {
+        &mut self.stdin
+    }
+
+    /// Returns the buffered stdout of this [`Process`].
+    pub fn stdout(&mut self) -> &mut BufReader<ChildStdout> {
+        &mut self.stdout
+    }
+
+    /// Writes the given `line` followed by a newline to the stdin of this
+    /// [`Process`] and flushes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if writing to the stdin fails.
+    pub async fn send_line(&mut self, line: &str) -> Result<(), Error> {
+        self.stdin.write_all(line.as_bytes()).await?;
+        self.stdin.write_all(b"\n").await?;
+        self.stdin.flush().await?;
+        Ok(())
+    }
+
+    /// Reads a line from the stdout of this [`Process`] without the
+    /// trailing newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Eof`] if the process closed its stdout or
+    /// [`Error::Io`] if reading from it fails.
+    pub async fn read_line(&mut self) -> Result<String, Error> {
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line).await? == 0 {
+            return Err(Error::Eof);
+        }
+        if line.ends_with('\n') {
+            let _ = line.pop();
+            if line.ends_with('\r') {
+                let _ = line.pop();
+            }
+        }
+        Ok(line)
+    }
+
+    /// Sends the given `line` using [`Process::send_line()`] and reads the
+    /// answer using [`Process::read_line()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Process::send_line()`] and [`Process::read_line()`].
+    pub async fn request(&mut self, line: &str) -> Result<String, Error> {
+        self.send_line(line).await?;
+        self.read_line().await
+    }
+}
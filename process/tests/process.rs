@@ -0,0 +1,113 @@
+//! End to end tests using `sh` and `cat` as worker processes.
+
+#![cfg(unix)]
+
+use std::time::Duration;
+
+use deadpool_process::{Config, ConfigError, Error, Handshake, Pool, PoolError, Runtime};
+
+fn cat_pool() -> Pool {
+    let mut cfg = Config::new("cat");
+    cfg.handshake = Some(Handshake::new("ping", "ping"));
+    cfg.create_pool(Some(Runtime::Tokio1)).unwrap()
+}
+
+#[tokio::test]
+async fn request() {
+    let pool = cat_pool();
+    let mut process = pool.get().await.unwrap();
+    assert_eq!(
+        process.request("Hello world!").await.unwrap(),
+        "Hello world!"
+    );
+}
+
+#[tokio::test]
+async fn process_is_reused() {
+    let pool = cat_pool();
+    let id = pool.get().await.unwrap().id();
+    assert!(id.is_some());
+    let mut process = pool.get().await.unwrap();
+    assert_eq!(process.id(), id);
+    assert_eq!(process.request("again").await.unwrap(), "again");
+}
+
+#[tokio::test]
+async fn unread_output_replaces_process() {
+    let pool = cat_pool();
+    let id = {
+        let mut process = pool.get().await.unwrap();
+        process.send_line("unread").await.unwrap();
+        process.id()
+    };
+    let mut process = pool.get().await.unwrap();
+    assert_ne!(process.id(), id);
+    assert_eq!(process.request("fresh").await.unwrap(), "fresh");
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn exited_process_is_replaced() {
+    // Answers the handshake and a single request before exiting.
+    let mut cfg = Config::new("sh");
+    cfg.args = vec![
+        "-c".into(),
+        r#"read line; echo "$line"; read line; echo "$line""#.into(),
+    ];
+    cfg.handshake = Some(Handshake::new("ping", "ping"));
+    let pool = cfg.create_pool(Some(Runtime::Tokio1)).unwrap();
+    let id = {
+        let mut process = pool.get().await.unwrap();
+        assert_eq!(process.request("last").await.unwrap(), "last");
+        assert!(matches!(process.read_line().await, Err(Error::Eof)));
+        process.id()
+    };
+    let process = pool.get().await.unwrap();
+    assert_ne!(process.id(), id);
+}
+
+#[tokio::test]
+async fn silent_process_times_out() {
+    let mut cfg = Config::new("sleep");
+    cfg.args = vec!["60".into()];
+    cfg.handshake = Some(Handshake::new("ping", "pong"));
+    cfg.handshake_timeout = Some(Duration::from_millis(100));
+    let pool = cfg.create_pool(Some(Runtime::Tokio1)).unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::Backend(Error::Timeout))
+    ));
+}
+
+#[tokio::test]
+async fn unexpected_response_is_rejected() {
+    let mut cfg = Config::new("cat");
+    cfg.handshake = Some(Handshake::new("ping", "pong"));
+    let pool = cfg.create_pool(Some(Runtime::Tokio1)).unwrap();
+    match pool.get().await {
+        Err(PoolError::Backend(Error::UnexpectedResponse(line))) => assert_eq!(line, "ping"),
+        result => panic!(
+            "Unexpected result: {:?}",
+            result.map(|process| process.id())
+        ),
+    }
+}
+
+#[tokio::test]
+async fn missing_program_is_rejected() {
+    assert!(matches!(
+        Config::default().builder(),
+        Err(ConfigError::MissingProgram)
+    ));
+}
+
+#[tokio::test]
+async fn unknown_program_fails_to_spawn() {
+    let pool = Config::new("deadpool-process-does-not-exist")
+        .create_pool(Some(Runtime::Tokio1))
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::Backend(Error::Io(_)))
+    ));
+}
@@ -0,0 +1,102 @@
+use std::convert::Infallible;
+
+use r2d2::ManageConnection;
+
+use crate::{CreatePoolError, Manager, ManagerConfig, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// The r2d2 manager itself is not part of the configuration and is passed
+/// to [`Config::create_pool()`] instead.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// R2D2__MANAGER__RECYCLING_METHOD=Fast
+/// R2D2__POOL__MAX_SIZE=16
+/// R2D2__POOL__TIMEOUTS__WAIT__SECS=5
+/// R2D2__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     r2d2: deadpool_r2d2::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// [`Manager`] configuration.
+    pub manager: Option<ManagerConfig>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Pool`] wrapping the given r2d2 `manager` using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool<M>(&self, manager: M, runtime: Runtime) -> Result<Pool<M>, CreatePoolError>
+    where
+        M: ManageConnection,
+        M::Error: Send,
+    {
+        self.builder(manager, runtime)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] wrapping the given r2d2 `manager` using
+    /// this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder<M>(&self, manager: M, runtime: Runtime) -> Result<PoolBuilder<M>, ConfigError>
+    where
+        M: ManageConnection,
+        M::Error: Send,
+    {
+        let manager = Manager::from_config(manager, runtime, self.get_manager_config());
+        Ok(Pool::builder(manager)
+            .config(self.get_pool_config())
+            .runtime(runtime))
+    }
+
+    /// Returns [`ManagerConfig`] which can be used to construct a
+    /// [`Manager`] instance.
+    #[must_use]
+    pub fn get_manager_config(&self) -> ManagerConfig {
+        self.manager.unwrap_or_default()
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the
+/// configuration.
+///
+/// This is just a type alias to [`Infallible`] at the moment as there
+/// is no validation happening at the configuration phase.
+pub type ConfigError = Infallible;
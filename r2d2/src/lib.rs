@@ -0,0 +1,33 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod manager;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+pub use r2d2;
+
+pub use self::{
+    config::{Config, ConfigError},
+    manager::{Manager, ManagerConfig, RecyclingMethod},
+};
+
+/// Type alias for using [`deadpool::managed::Pool`] with [`r2d2`].
+pub type Pool<M> = deadpool::managed::Pool<Manager<M>>;
+
+/// Type alias for using [`deadpool::managed::PoolBuilder`] with [`r2d2`].
+pub type PoolBuilder<M> = deadpool::managed::PoolBuilder<Manager<M>>;
+
+/// Type alias for using [`deadpool::managed::BuildError`] with [`r2d2`].
+pub type BuildError = deadpool::managed::BuildError;
+
+/// Type alias for using [`deadpool::managed::CreatePoolError`] with
+/// [`r2d2`].
+pub type CreatePoolError = deadpool::managed::CreatePoolError<ConfigError>;
+
+/// Type alias for using [`deadpool::managed::PoolError`] with [`r2d2`].
+pub type PoolError<M> = deadpool::managed::PoolError<<M as r2d2::ManageConnection>::Error>;
+
+/// Type alias for using [`deadpool::managed::Object`] with [`r2d2`].
+pub type Connection<M> = deadpool::managed::Object<Manager<M>>;
@@ -0,0 +1,220 @@
+use std::{fmt, sync::Arc};
+
+use deadpool::{
+    managed::{self, Metrics, RecycleError, RecycleResult},
+    Runtime,
+};
+use deadpool_sync::SyncWrapper;
+use r2d2::ManageConnection;
+
+/// [`Manager`] which runs an [`r2d2::ManageConnection`] on the blocking
+/// thread pool of the [`Runtime`].
+///
+/// See the [`deadpool` documentation](deadpool) for usage examples.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager<M> {
+    manager: Arc<M>,
+    runtime: Runtime,
+    config: ManagerConfig,
+}
+
+// Implemented manually as `ManageConnection` doesn't require `Debug`.
+impl<M> fmt::Debug for Manager<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("runtime", &self.runtime)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> Manager<M> {
+    /// Creates a new [`Manager`] wrapping the given r2d2 `manager` using the
+    /// default [`ManagerConfig`].
+    #[must_use]
+    pub fn new(manager: M, runtime: Runtime) -> Self {
+        Self::from_config(manager, runtime, ManagerConfig::default())
+    }
+
+    /// Creates a new [`Manager`] wrapping the given r2d2 `manager` using the
+    /// provided [`ManagerConfig`].
+    #[must_use]
+    pub fn from_config(manager: M, runtime: Runtime, config: ManagerConfig) -> Self {
+        Self {
+            manager: Arc::new(manager),
+            runtime,
+            config,
+        }
+    }
+
+    /// Returns the wrapped r2d2 manager.
+    #[must_use]
+    pub fn inner(&self) -> &M {
+        &self.manager
+    }
+}
+
+impl<M> managed::Manager for Manager<M>
+where
+    M: ManageConnection,
+    M::Error: Send,
+{
+    type Type = SyncWrapper<M::Connection>;
+    type Error = M::Error;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        let manager = self.manager.clone();
+        SyncWrapper::new(self.runtime, move || manager.connect()).await
+    }
+
+    async fn recycle(&self, obj: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
+        if obj.is_mutex_poisoned() {
+            return Err(RecycleError::Message(
+                "Mutex is poisoned. Connection is considered unusable.".into(),
+            ));
+        }
+        let manager = self.manager.clone();
+        let recycling_method = self.config.recycling_method;
+        obj.interact(move |conn| {
+            if manager.has_broken(conn) {
+                return Err(RecycleError::Message(
+                    "Connection is broken. Connection is considered unusable.".into(),
+                ));
+            }
+            match recycling_method {
+                RecyclingMethod::Fast => Ok(()),
+                RecyclingMethod::Verified => manager.is_valid(conn).map_err(RecycleError::Backend),
+            }
+        })
+        .await
+        .map_err(|e| RecycleError::message(format!("Panic: {e:?}")))?
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+///
+/// [`Pool`]: crate::Pool
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which matches the `test_on_check_out`
+/// default of r2d2.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Only run [`ManageConnection::has_broken()`] when recycling existing
+    /// connections.
+    Fast,
+
+    /// Run [`ManageConnection::has_broken()`] followed by
+    /// [`ManageConnection::is_valid()`] when recycling existing
+    /// connections.
+    #[default]
+    Verified,
+}
+
+#[cfg(all(test, feature = "rt_tokio_1"))]
+mod tests {
+    use std::{
+        io,
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    };
+
+    use crate::{Config, Pool, PoolConfig};
+
+    use super::*;
+
+    /// In-memory r2d2 manager whose connections are numbered in the order
+    /// they were created.
+    #[derive(Default)]
+    struct TestManager {
+        connects: AtomicUsize,
+        validations: AtomicUsize,
+        broken: AtomicBool,
+    }
+
+    impl ManageConnection for TestManager {
+        type Connection = usize;
+        type Error = io::Error;
+
+        fn connect(&self) -> Result<usize, io::Error> {
+            Ok(self.connects.fetch_add(1, Ordering::Relaxed))
+        }
+
+        fn is_valid(&self, _: &mut usize) -> Result<(), io::Error> {
+            let _ = self.validations.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn has_broken(&self, _: &mut usize) -> bool {
+            self.broken.load(Ordering::Relaxed)
+        }
+    }
+
+    fn pool(recycling_method: RecyclingMethod) -> Pool<TestManager> {
+        let config = Config {
+            manager: Some(ManagerConfig { recycling_method }),
+            pool: Some(PoolConfig::new(1)),
+        };
+        config
+            .create_pool(TestManager::default(), Runtime::Tokio1)
+            .unwrap()
+    }
+
+    async fn get(pool: &Pool<TestManager>) -> usize {
+        let conn = pool.get().await.unwrap();
+        conn.interact(|conn| *conn).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn connections_are_created_and_reused() {
+        let pool = pool(RecyclingMethod::Fast);
+        assert_eq!(get(&pool).await, 0);
+        assert_eq!(get(&pool).await, 0);
+        assert_eq!(pool.manager().inner().connects.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn fast_recycling_skips_is_valid() {
+        let pool = pool(RecyclingMethod::Fast);
+        for _ in 0..3 {
+            let _ = get(&pool).await;
+        }
+        let manager = pool.manager().inner();
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn verified_recycling_runs_is_valid() {
+        let pool = pool(RecyclingMethod::Verified);
+        for _ in 0..3 {
+            let _ = get(&pool).await;
+        }
+        let manager = pool.manager().inner();
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn broken_connections_are_replaced() {
+        let pool = pool(RecyclingMethod::Verified);
+        assert_eq!(get(&pool).await, 0);
+        pool.manager().inner().broken.store(true, Ordering::Relaxed);
+        assert_eq!(get(&pool).await, 1);
+        let manager = pool.manager().inner();
+        assert_eq!(manager.connects.load(Ordering::Relaxed), 2);
+        assert_eq!(manager.validations.load(Ordering::Relaxed), 0);
+    }
+}
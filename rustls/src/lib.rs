@@ -0,0 +1,109 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+use std::{fmt, path::Path, sync::Arc};
+
+use rustls::{
+    crypto::ring,
+    pki_types::{pem, pem::PemObject, CertificateDer},
+    ClientConfig, RootCertStore,
+};
+
+pub use rustls;
+
+/// Creates a [`ClientConfig`] verifying servers against the CA certificates
+/// of the given PEM `ca_file` or the Mozilla root certificates if it is
+/// [`None`].
+///
+/// # Errors
+///
+/// See [`Error`] for details.
+pub fn client_config(ca_file: Option<&Path>) -> Result<ClientConfig, Error> {
+    let mut roots = RootCertStore::empty();
+    match ca_file {
+        Some(ca_file) => {
+            for cert in CertificateDer::pem_file_iter(ca_file)? {
+                roots.add(cert?)?;
+            }
+        }
+        None => roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+    }
+    let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(config)
+}
+
+/// Possible errors returned by [`client_config()`].
+#[derive(Debug)]
+pub enum Error {
+    /// The CA file could not be read or contains invalid PEM data.
+    Pem(pem::Error),
+
+    /// A CA certificate is invalid or the crypto provider doesn't support
+    /// the default protocol versions.
+    Rustls(rustls::Error),
+}
+
+impl From<pem::Error> for Error {
+    fn from(e: pem::Error) -> Self {
+        Self::Pem(e)
+    }
+}
+
+impl From<rustls::Error> for Error {
+    fn from(e: rustls::Error) -> Self {
+        Self::Rustls(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pem(e) => write!(f, "Invalid CA file: {e}"),
+            Self::Rustls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Pem(e) => Some(e),
+            Self::Rustls(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn default_roots_are_used_without_ca_file() {
+        assert!(client_config(None).is_ok());
+    }
+
+    #[test]
+    fn missing_ca_file_is_rejected() {
+        let ca_file = env::temp_dir().join("deadpool-rustls-missing.pem");
+        let result = client_config(Some(&ca_file));
+        assert!(matches!(result, Err(Error::Pem(_))));
+    }
+
+    #[test]
+    fn invalid_certificate_is_rejected() {
+        let ca_file = env::temp_dir().join("deadpool-rustls-invalid.pem");
+        fs::write(
+            &ca_file,
+            "-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n",
+        )
+        .unwrap();
+        let result = client_config(Some(&ca_file));
+        fs::remove_file(&ca_file).unwrap();
+        assert!(matches!(result, Err(Error::Rustls(_))));
+    }
+}
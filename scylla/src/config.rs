@@ -0,0 +1,193 @@
+use std::{fmt, time::Duration};
+
+use scylla::client::session_builder::SessionBuilder;
+
+#[cfg(feature = "rustls")]
+use crate::TlsConfig;
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// SCYLLA__CONTACT_POINTS=node1:9042,node2:9042
+/// SCYLLA__KEYSPACE=deadpool
+/// SCYLLA__USERNAME=deadpool
+/// SCYLLA__PASSWORD=topsecret
+/// SCYLLA__POOL__MAX_SIZE=4
+/// SCYLLA__POOL__TIMEOUTS__WAIT__SECS=5
+/// SCYLLA__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     scylla: deadpool_scylla::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(
+///                 config::Environment::default()
+///                     .separator("__")
+///                     .list_separator(",")
+///                     .with_list_parse_key("scylla.contact_points")
+///                     .try_parsing(true),
+///             )
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Addresses (`host:port`) of the nodes used to discover the cluster.
+    pub contact_points: Vec<String>,
+
+    /// Keyspace used by every session.
+    pub keyspace: Option<String>,
+
+    /// Username used for authentication.
+    pub username: Option<String>,
+
+    /// Password used for authentication.
+    pub password: Option<String>,
+
+    /// Name of the datacenter whose nodes are preferred by the load
+    /// balancing policy.
+    pub local_datacenter: Option<String>,
+
+    /// Timeout for establishing connections to the nodes.
+    pub connection_timeout: Option<Duration>,
+
+    /// TLS configuration. Plain TCP is used if this is not set.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration.
+    ///
+    /// Every object of the [`Pool`] is a full session with its own
+    /// connections to the cluster, so the `max_size` should be small.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given contact points.
+    #[must_use]
+    pub fn from_contact_points<I, S>(contact_points: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            contact_points: contact_points.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns a [`SessionBuilder`] which can be used to create sessions.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn get_session_builder(&self) -> Result<SessionBuilder, ConfigError> {
+        if self.contact_points.is_empty() {
+            return Err(ConfigError::NoContactPoints);
+        }
+        let mut builder = SessionBuilder::new().known_nodes(&self.contact_points);
+        match (&self.username, &self.password) {
+            (Some(username), Some(password)) => builder = builder.user(username, password),
+            (None, None) => {}
+            _ => return Err(ConfigError::IncompleteCredentials),
+        }
+        if let Some(keyspace) = &self.keyspace {
+            builder = builder.use_keyspace(keyspace, false);
+        }
+        if let Some(local_datacenter) = &self.local_datacenter {
+            builder = builder.prefer_datacenter(local_datacenter.clone());
+        }
+        if let Some(connection_timeout) = self.connection_timeout {
+            builder = builder.connection_timeout(connection_timeout);
+        }
+        #[cfg(feature = "rustls")]
+        if let Some(tls) = &self.tls {
+            builder = builder.tls_context(Some(tls.client_config()?));
+        }
+        Ok(builder)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the scylla
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No contact point was configured.
+    NoContactPoints,
+
+    /// Only one of [`Config::username`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoContactPoints => write!(f, "No contact point configured"),
+            Self::IncompleteCredentials => {
+                write!(f, "Username and password must be configured together")
+            }
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use scylla::errors::{ExecutionError, NewSessionError};
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Establishing a session failed.
+    Session(NewSessionError),
+
+    /// Verifying a session using a query failed.
+    Query(ExecutionError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Session(e) => write!(f, "Failed to establish session: {e}"),
+            Self::Query(e) => write!(f, "Failed to verify session: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Session(e) => Some(e),
+            Self::Query(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+mod statement_cache;
+#[cfg(feature = "rustls")]
+mod tls;
+
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use deadpool::managed;
+use scylla::{
+    client::{session::Session, session_builder::SessionBuilder},
+    errors::PrepareError,
+    statement::prepared::PreparedStatement,
+};
+
+pub use scylla;
+
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+pub use self::tls::TlsConfig;
+pub use self::{
+    config::{Config, ConfigError},
+    error::Error,
+    statement_cache::StatementCache,
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "scylla",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Query used to verify sessions before they are handed out again.
+const VERIFY_QUERY: &str = "SELECT key FROM system.local";
+
+/// [`Manager`] for creating and recycling [`scylla::client::session::Session`]s.
+///
+/// **Important:** Every created [`Session`] has its own connections to
+/// every shard of every node, so the number of connections to the cluster
+/// grows with the size of the [`Pool`]. Keep its `max_size` small.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    builder: SessionBuilder,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`SessionBuilder`].
+    #[must_use]
+    pub fn new(builder: SessionBuilder) -> Self {
+        Self { builder }
+    }
+
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Ok(Self::new(config.get_session_builder()?))
+    }
+}
+
+// Implemented manually as `SessionBuilder` doesn't implement `Debug`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager").finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = SessionWrapper;
+    type Error = Error;
+
+    async fn create(&self) -> Result<SessionWrapper, Error> {
+        let session = self.builder.build().await.map_err(Error::Session)?;
+        Ok(SessionWrapper::new(session))
+    }
+
+    async fn recycle(&self, session: &mut SessionWrapper, _: &Metrics) -> RecycleResult {
+        let _ = session
+            .query_unpaged(VERIFY_QUERY, &[])
+            .await
+            .map_err(Error::Query)?;
+        Ok(())
+    }
+}
+
+/// Wrapper around [`scylla::client::session::Session`] with a
+/// [`StatementCache`].
+#[derive(Debug)]
+pub struct SessionWrapper {
+    /// Original [`Session`].
+    session: Session,
+
+    /// [`StatementCache`] of this session.
+    pub statement_cache: StatementCache,
+}
+
+impl SessionWrapper {
+    /// Create a new [`SessionWrapper`] instance using the given
+    /// [`Session`].
+    #[must_use]
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            statement_cache: StatementCache::default(),
+        }
+    }
+
+    /// Like [`Session::prepare()`], but uses an existing
+    /// [`PreparedStatement`] from the [`StatementCache`] if possible.
+    ///
+    /// # Errors
+    ///
+    /// See [`PrepareError`] for details.
+    pub async fn prepare_cached(&self, query: &str) -> Result<PreparedStatement, PrepareError> {
+        self.statement_cache.prepare(&self.session, query).await
+    }
+}
+
+impl Deref for SessionWrapper {
+    type Target = Session;
+
+    fn deref(&self) -> &Session {
+        &self.session
+    }
+}
+
+impl DerefMut for SessionWrapper {
+    fn deref_mut(&mut self) -> &mut Session {
+        &mut self.session
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::HashMap, future::Future, sync::RwLock};
+
+use scylla::{
+    client::session::Session, errors::PrepareError, statement::prepared::PreparedStatement,
+};
+
+/// Representation of a cache of [`PreparedStatement`]s.
+///
+/// [`StatementCache`] is bound to one [`SessionWrapper`](crate::SessionWrapper)
+/// and the statements are keyed by their query string.
+#[derive(Debug, Default)]
+pub struct StatementCache {
+    map: StatementMap<PreparedStatement>,
+}
+
+impl StatementCache {
+    /// Returns the current size of this [`StatementCache`].
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    /// Clears this [`StatementCache`].
+    pub fn clear(&self) {
+        self.map.clear();
+    }
+
+    /// Removes a [`PreparedStatement`] from this [`StatementCache`].
+    ///
+    /// **Important:** This only removes the statement from the cache. The
+    /// statement itself is still prepared on the database nodes.
+    pub fn remove(&self, query: &str) -> Option<PreparedStatement> {
+        self.map.remove(query)
+    }
+
+    /// Creates a new prepared statement using the [`StatementCache`], if
+    /// possible.
+    ///
+    /// # Errors
+    ///
+    /// See [`PrepareError`] for details.
+    pub async fn prepare(
+        &self,
+        session: &Session,
+        query: &str,
+    ) -> Result<PreparedStatement, PrepareError> {
+        self.map
+            .get_or_prepare(query, || session.prepare(query))
+            .await
+    }
+}
+
+/// Statements keyed by their query string.
+///
+/// This is generic over the statement so the caching can be tested without
+/// a database as [`PreparedStatement`]s can only be created by a
+/// [`Session`].
+#[derive(Debug)]
+struct StatementMap<S> {
+    map: RwLock<HashMap<String, S>>,
+}
+
+impl<S> Default for StatementMap<S> {
+    fn default() -> Self {
+        Self {
+            map: RwLock::default(),
+        }
+    }
+}
+
+impl<S: Clone> StatementMap<S> {
+    fn size(&self) -> usize {
+        self.map.read().unwrap().len()
+    }
+
+    fn clear(&self) {
+        self.map.write().unwrap().clear();
+    }
+
+    fn remove(&self, query: &str) -> Option<S> {
+        self.map.write().unwrap().remove(query)
+    }
+
+    fn get(&self, query: &str) -> Option<S> {
+        self.map.read().unwrap().get(query).cloned()
+    }
+
+    fn insert(&self, query: &str, statement: S) {
+        let _ = self
+            .map
+            .write()
+            .unwrap()
+            .insert(query.to_owned(), statement);
+    }
+
+    async fn get_or_prepare<F, Fut, E>(&self, query: &str, prepare: F) -> Result<S, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<S, E>>,
+    {
+        if let Some(statement) = self.get(query) {
+            return Ok(statement);
+        }
+        let statement = prepare().await?;
+        self.insert(query, statement.clone());
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    async fn prepare(
+        map: &StatementMap<String>,
+        prepared: &AtomicUsize,
+        query: &str,
+    ) -> Result<String, ()> {
+        map.get_or_prepare(query, || async {
+            let _ = prepared.fetch_add(1, Ordering::Relaxed);
+            Ok(format!("prepared {query}"))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn statements_are_cached_by_query() {
+        let map = StatementMap::default();
+        let prepared = AtomicUsize::new(0);
+        assert_eq!(
+            prepare(&map, &prepared, "SELECT 1").await.unwrap(),
+            "prepared SELECT 1"
+        );
+        assert_eq!(
+            prepare(&map, &prepared, "SELECT 1").await.unwrap(),
+            "prepared SELECT 1"
+        );
+        assert_eq!(prepared.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            prepare(&map, &prepared, "SELECT 2").await.unwrap(),
+            "prepared SELECT 2"
+        );
+        assert_eq!(prepared.load(Ordering::Relaxed), 2);
+        assert_eq!(map.size(), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let map = StatementMap::<String>::default();
+        let result = map
+            .get_or_prepare("SELECT", || async { Err::<String, _>("syntax error") })
+            .await;
+        assert_eq!(result, Err("syntax error"));
+        assert_eq!(map.size(), 0);
+        let prepared = AtomicUsize::new(0);
+        assert!(prepare(&map, &prepared, "SELECT").await.is_ok());
+        assert_eq!(prepared.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn removed_statements_are_prepared_again() {
+        let map = StatementMap::default();
+        let prepared = AtomicUsize::new(0);
+        let _ = prepare(&map, &prepared, "SELECT 1").await.unwrap();
+        let _ = prepare(&map, &prepared, "SELECT 2").await.unwrap();
+        assert_eq!(map.remove("SELECT 1").as_deref(), Some("prepared SELECT 1"));
+        assert_eq!(map.remove("SELECT 1"), None);
+        assert_eq!(map.size(), 1);
+        let _ = prepare(&map, &prepared, "SELECT 1").await.unwrap();
+        assert_eq!(prepared.load(Ordering::Relaxed), 3);
+        map.clear();
+        assert_eq!(map.size(), 0);
+        let _ = prepare(&map, &prepared, "SELECT 2").await.unwrap();
+        assert_eq!(prepared.load(Ordering::Relaxed), 4);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{fmt, time::Duration};
+
+use lettre::transport::smtp::{
+    authentication::{Mechanism, DEFAULT_MECHANISMS},
+    SMTP_PORT, SUBMISSIONS_PORT, SUBMISSION_PORT,
+};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// SMTP__HOST=smtp.example.com
+/// SMTP__TLS=Required
+/// SMTP__USERNAME=deadpool
+/// SMTP__PASSWORD=topsecret
+/// SMTP__POOL__MAX_SIZE=4
+/// SMTP__POOL__TIMEOUTS__WAIT__SECS=5
+/// SMTP__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     smtp: deadpool_smtp::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Host name of the SMTP relay.
+    pub host: Option<String>,
+
+    /// Port of the SMTP relay.
+    ///
+    /// Defaults to the port matching [`Config::tls`]: `465` for
+    /// [`TlsMode::Wrapper`], `25` for [`TlsMode::None`] and `587` otherwise.
+    pub port: Option<u16>,
+
+    /// How the connection is encrypted. See [`TlsMode`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tls: TlsMode,
+
+    /// Name sent with `EHLO`.
+    ///
+    /// Defaults to the host name of this machine.
+    pub hello_name: Option<String>,
+
+    /// Username used for authentication.
+    pub username: Option<String>,
+
+    /// Password used for authentication.
+    pub password: Option<String>,
+
+    /// Allowed authentication mechanisms in order of preference.
+    ///
+    /// Defaults to `PLAIN` and `LOGIN`.
+    pub mechanisms: Option<Vec<Mechanism>>,
+
+    /// Timeout for connecting and for every SMTP command.
+    pub timeout: Option<Duration>,
+
+    /// Accept invalid certificates of the relay.
+    ///
+    /// **Important:** This makes the connection vulnerable to
+    /// man-in-the-middle attacks and should only be used for testing.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accept_invalid_certs: bool,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given relay `host`.
+    #[must_use]
+    pub fn from_host<T: Into<String>>(host: T) -> Self {
+        Self {
+            host: Some(host.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the configured [`Config::port`] or the default port of the
+    /// [`TlsMode`].
+    #[must_use]
+    pub fn get_port(&self) -> u16 {
+        self.port.unwrap_or(match self.tls {
+            TlsMode::None => SMTP_PORT,
+            TlsMode::Opportunistic | TlsMode::Required => SUBMISSION_PORT,
+            TlsMode::Wrapper => SUBMISSIONS_PORT,
+        })
+    }
+
+    /// Returns the configured [`Config::mechanisms`] or the default ones.
+    #[must_use]
+    pub fn get_mechanisms(&self) -> Vec<Mechanism> {
+        self.mechanisms
+            .clone()
+            .unwrap_or_else(|| DEFAULT_MECHANISMS.to_vec())
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    pub(crate) fn get_tls_parameters(
+        &self,
+        host: &str,
+    ) -> Result<Option<lettre::transport::smtp::client::TlsParameters>, ConfigError> {
+        if self.tls == TlsMode::None {
+            return Ok(None);
+        }
+        lettre::transport::smtp::client::TlsParameters::builder(host.to_owned())
+            .dangerous_accept_invalid_certs(self.accept_invalid_certs)
+            .build()
+            .map(Some)
+            .map_err(ConfigError::Tls)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Possible modes of how a connection is encrypted.
+///
+/// The default is [`Required`] which upgrades the connection using
+/// `STARTTLS` and fails if the relay doesn't support it.
+///
+/// [`Required`]: TlsMode::Required
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TlsMode {
+    /// Don't encrypt the connection.
+    None,
+
+    /// Use `STARTTLS` if the relay supports it.
+    ///
+    /// **Important:** An attacker can downgrade the connection to plain
+    /// text by hiding the support for `STARTTLS`.
+    Opportunistic,
+
+    /// Use `STARTTLS` and fail if the relay doesn't support it.
+    #[default]
+    Required,
+
+    /// Use implicit TLS ("SMTPS") right after connecting.
+    Wrapper,
+}
+
+/// This error is returned if there is something wrong with the SMTP
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `Tls` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::host`] was specified.
+    MissingHost,
+
+    /// Only one of [`Config::username`] and [`Config::password`] was set.
+    IncompleteCredentials,
+
+    /// A [`TlsMode`] other than [`TlsMode::None`] was configured without
+    /// enabling the `rustls` or `native-tls` feature.
+    #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+    TlsNotSupported,
+
+    /// The TLS parameters could not be created.
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "rustls", feature = "native-tls"))))]
+    Tls(lettre::transport::smtp::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHost => write!(f, "No host specified"),
+            Self::IncompleteCredentials => {
+                write!(f, "Username and password must be configured together")
+            }
+            #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+            Self::TlsNotSupported => write!(f, "TLS requires the `rustls` or `native-tls` feature"),
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            Self::Tls(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use deadpool::managed::{self, RecycleError};
+use lettre::transport::smtp::{
+    authentication::{Credentials, Mechanism},
+    client::AsyncSmtpConnection,
+    extension::ClientId,
+    Error,
+};
+
+pub use lettre;
+
+pub use self::config::{Config, ConfigError, TlsMode};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "lettre",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling SMTP connections.
+///
+/// New connections are connected, greeted with `EHLO`, encrypted according
+/// to the [`TlsMode`] and authenticated before they are handed out.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    host: String,
+    port: u16,
+    tls_mode: TlsMode,
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    tls_parameters: Option<lettre::transport::smtp::client::TlsParameters>,
+    hello_name: ClientId,
+    credentials: Option<Credentials>,
+    mechanisms: Vec<Mechanism>,
+    timeout: Option<Duration>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let host = config.host.clone().ok_or(ConfigError::MissingHost)?;
+        let credentials = match (&config.username, &config.password) {
+            (Some(username), Some(password)) => {
+                Some(Credentials::new(username.clone(), password.clone()))
+            }
+            (None, None) => None,
+            _ => return Err(ConfigError::IncompleteCredentials),
+        };
+        #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+        if config.tls != TlsMode::None {
+            return Err(ConfigError::TlsNotSupported);
+        }
+        Ok(Self {
+            port: config.get_port(),
+            tls_mode: config.tls,
+            #[cfg(any(feature = "rustls", feature = "native-tls"))]
+            tls_parameters: config.get_tls_parameters(&host)?,
+            host,
+            hello_name: config
+                .hello_name
+                .clone()
+                .map(ClientId::Domain)
+                .unwrap_or_default(),
+            credentials,
+            mechanisms: config.get_mechanisms(),
+            timeout: config.timeout,
+        })
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    async fn starttls(&self, conn: &mut AsyncSmtpConnection) -> Result<(), Error> {
+        let Some(tls_parameters) = &self.tls_parameters else {
+            return Ok(());
+        };
+        match self.tls_mode {
+            TlsMode::Required => {
+                conn.starttls(tls_parameters.clone(), &self.hello_name)
+                    .await
+            }
+            TlsMode::Opportunistic if conn.can_starttls() => {
+                conn.starttls(tls_parameters.clone(), &self.hello_name)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// Implemented manually as the TLS parameters don't implement `Debug`.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("tls_mode", &self.tls_mode)
+            .field("hello_name", &self.hello_name)
+            .field("credentials", &self.credentials)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        #[cfg(any(feature = "rustls", feature = "native-tls"))]
+        let wrapper_tls = match self.tls_mode {
+            TlsMode::Wrapper => self.tls_parameters.clone(),
+            _ => None,
+        };
+        #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+        let wrapper_tls = None;
+        let mut conn = AsyncSmtpConnection::connect_tokio1(
+            (self.host.as_str(), self.port),
+            self.timeout,
+            &self.hello_name,
+            wrapper_tls,
+            None,
+        )
+        .await?;
+        #[cfg(any(feature = "rustls", feature = "native-tls"))]
+        self.starttls(&mut conn).await?;
+        if let Some(credentials) = &self.credentials {
+            let _ = conn.auth(&self.mechanisms, credentials).await?;
+        }
+        Ok(Connection { conn })
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult {
+        if conn.has_broken() {
+            return Err(RecycleError::message("Connection broken"));
+        }
+        if !conn.test_connected().await {
+            return Err(RecycleError::message("NOOP failed"));
+        }
+        Ok(())
+    }
+}
+
+/// Wrapper around [`AsyncSmtpConnection`].
+pub struct Connection {
+    conn: AsyncSmtpConnection,
+}
+
+// Implemented manually as `AsyncSmtpConnection` doesn't implement `Debug`.
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("server_info", self.conn.server_info())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Connection {
+    type Target = AsyncSmtpConnection;
+
+    fn deref(&self) -> &AsyncSmtpConnection {
+        &self.conn
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut AsyncSmtpConnection {
+        &mut self.conn
+    }
+}
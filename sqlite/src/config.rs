@@ -0,0 +1,138 @@
+use std::{collections::BTreeMap, convert::Infallible, path::PathBuf};
+
+use rusqlite::OpenFlags;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// SQLITE__PATH=db.sqlite3
+/// SQLITE__PRAGMAS__JOURNAL_MODE=WAL
+/// SQLITE__PRAGMAS__BUSY_TIMEOUT=5000
+/// SQLITE__POOL__MAX_SIZE=16
+/// SQLITE__POOL__TIMEOUTS__WAIT__SECS=5
+/// SQLITE__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     sqlite: deadpool_sqlite::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Path to SQLite database file.
+    ///
+    /// `file:` URIs are supported. An empty path or `:memory:` creates an
+    /// in-memory database which is shared by all connections of the pool.
+    /// **Important:** Such a database is deleted as soon as the last
+    /// connection of the pool is closed.
+    pub path: PathBuf,
+
+    /// Mode used to open the database. See [`OpenMode`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub open_mode: OpenMode,
+
+    /// Pragmas set on every new connection, e.g. `journal_mode = WAL` or
+    /// `busy_timeout = 5000`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pragmas: BTreeMap<String, String>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Create a new [`Config`] with the given `path` of SQLite database file.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Runtime) -> Result<Pool, CreatePoolError> {
+        self.builder(runtime)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self, runtime: Runtime) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self, runtime);
+        Ok(Pool::builder(manager)
+            .config(self.get_pool_config())
+            .runtime(runtime))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Possible modes of opening a database.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum OpenMode {
+    /// Open the database for reading and writing and create it if it does
+    /// not exist.
+    #[default]
+    ReadWriteCreate,
+
+    /// Open the database for reading and writing. Fails if the database
+    /// does not exist.
+    ReadWrite,
+
+    /// Open the database for reading only.
+    ReadOnly,
+}
+
+impl OpenMode {
+    /// Returns the [`OpenFlags`] used for opening connections in this mode.
+    #[must_use]
+    pub fn flags(self) -> OpenFlags {
+        let flags = match self {
+            Self::ReadWriteCreate => {
+                OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE
+            }
+            Self::ReadWrite => OpenFlags::SQLITE_OPEN_READ_WRITE,
+            Self::ReadOnly => OpenFlags::SQLITE_OPEN_READ_ONLY,
+        };
+        flags | OpenFlags::SQLITE_OPEN_NO_MUTEX | OpenFlags::SQLITE_OPEN_URI
+    }
+}
+
+/// This error is returned if there is something wrong with the SQLite configuration.
+///
+/// This is just a type alias to [`Infallible`] at the moment as there
+/// is no validation happening at the configuration phase.
+pub type ConfigError = Infallible;
@@ -0,0 +1,118 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+
+use std::{
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicI64, AtomicUsize, Ordering},
+};
+
+use deadpool::managed::{self, RecycleError};
+use deadpool_sync::SyncWrapper;
+
+pub use deadpool::managed::reexports::*;
+pub use deadpool_sync::reexports::*;
+pub use rusqlite;
+
+deadpool::managed_reexports!(
+    "rusqlite",
+    Manager,
+    managed::Object<Manager>,
+    rusqlite::Error,
+    ConfigError
+);
+
+pub use self::config::{Config, ConfigError, OpenMode};
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
+
+/// Counter used to give every in-memory database its own name.
+static MEMORY_DB_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// [`Manager`] for creating and recycling SQLite [`Connection`]s.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    config: Config,
+    path: PathBuf,
+    recycle_count: AtomicI64,
+    runtime: Runtime,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`] backed by the
+    /// specified [`Runtime`].
+    ///
+    /// If [`Config::path`] is empty or `:memory:` the connections of this
+    /// [`Manager`] share a single in-memory database instead of every
+    /// connection opening a private one.
+    #[must_use]
+    pub fn from_config(config: &Config, runtime: Runtime) -> Self {
+        let path = if is_private_memory_db(&config.path) {
+            PathBuf::from(format!(
+                "file:deadpool-sqlite-{}-{}?mode=memory&cache=shared",
+                process::id(),
+                MEMORY_DB_COUNTER.fetch_add(1, Ordering::Relaxed),
+            ))
+        } else {
+            config.path.clone()
+        };
+        Self {
+            config: config.clone(),
+            path,
+            recycle_count: AtomicI64::new(0),
+            runtime,
+        }
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = SyncWrapper<rusqlite::Connection>;
+    type Error = rusqlite::Error;
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        let path = self.path.clone();
+        let flags = self.config.open_mode.flags();
+        let pragmas = self.config.pragmas.clone();
+        SyncWrapper::new(self.runtime, move || {
+            let conn = rusqlite::Connection::open_with_flags(path, flags)?;
+            for (name, value) in &pragmas {
+                conn.pragma_update(None, name, value)?;
+            }
+            Ok(conn)
+        })
+        .await
+    }
+
+    async fn recycle(
+        &self,
+        conn: &mut Self::Type,
+        _: &Metrics,
+    ) -> managed::RecycleResult<Self::Error> {
+        if conn.is_mutex_poisoned() {
+            return Err(RecycleError::Message(
+                "Mutex is poisoned. Connection is considered unusable.".into(),
+            ));
+        }
+        let recycle_count = self.recycle_count.fetch_add(1, Ordering::Relaxed);
+        let n: i64 = conn
+            .interact(move |conn| conn.query_row("SELECT $1", [recycle_count], |row| row.get(0)))
+            .await
+            .map_err(|e| RecycleError::message(format!("{e}")))??;
+        if n == recycle_count {
+            Ok(())
+        } else {
+            Err(RecycleError::message("Recycle count mismatch"))
+        }
+    }
+}
+
+/// Returns `true` if opening the given `path` would create a database only
+/// visible to a single connection.
+fn is_private_memory_db(path: &Path) -> bool {
+    path.as_os_str().is_empty() || path == Path::new(":memory:")
+}
@@ -0,0 +1,61 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    rustdoc::private_intra_doc_links
+)]
+#![forbid(non_ascii_idents, unsafe_code)]
+#![warn(
+    deprecated_in_future,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    unreachable_pub,
+    unused_import_braces,
+    unused_labels,
+    unused_lifetimes,
+    unused_qualifications,
+    unused_results
+)]
+
+mod config;
+mod manager;
+
+#[cfg(feature = "mysql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+pub mod mysql;
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub mod postgres;
+#[cfg(feature = "sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+pub mod sqlite;
+
+pub use deadpool::managed::reexports::*;
+pub use sqlx;
+
+pub use self::{
+    config::{Config, ConfigError},
+    manager::{Manager, ManagerConfig, RecyclingMethod},
+};
+
+/// Type alias for using [`deadpool::managed::Pool`] with [`sqlx`].
+pub type Pool<C> = deadpool::managed::Pool<Manager<C>>;
+
+/// Type alias for using [`deadpool::managed::PoolBuilder`] with [`sqlx`].
+pub type PoolBuilder<C> = deadpool::managed::PoolBuilder<Manager<C>>;
+
+/// Type alias for using [`deadpool::managed::BuildError`] with [`sqlx`].
+pub type BuildError = deadpool::managed::BuildError;
+
+/// Type alias for using [`deadpool::managed::CreatePoolError`] with
+/// [`sqlx`].
+pub type CreatePoolError = deadpool::managed::CreatePoolError<ConfigError>;
+
+/// Type alias for using [`deadpool::managed::PoolError`] with [`sqlx`].
+pub type PoolError = deadpool::managed::PoolError<sqlx::Error>;
+
+/// Type alias for using [`deadpool::managed::Object`] with [`sqlx`].
+pub type Connection<C> = deadpool::managed::Object<Manager<C>>;
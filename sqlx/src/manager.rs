@@ -0,0 +1,103 @@
+use std::fmt;
+
+use deadpool::managed::{self, Metrics, RecycleResult};
+use sqlx::{ConnectOptions, Connection};
+
+/// [`Connection`] [`Manager`] for use with [`sqlx`].
+///
+/// See the [`deadpool` documentation](deadpool) for usage examples.
+///
+/// [`Connection`]: crate::Connection
+/// [`Manager`]: managed::Manager
+pub struct Manager<C: Connection> {
+    options: C::Options,
+    config: ManagerConfig,
+}
+
+// Implemented manually to not leak the password of the connect options.
+impl<C: Connection> fmt::Debug for Manager<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Connection> Manager<C> {
+    /// Creates a new [`Manager`] which establishes [`Connection`]s using the
+    /// given connect `options` and the default [`ManagerConfig`].
+    ///
+    /// [`Connection`]: crate::Connection
+    #[must_use]
+    pub fn new(options: C::Options) -> Self {
+        Self::from_config(options, ManagerConfig::default())
+    }
+
+    /// Creates a new [`Manager`] which establishes [`Connection`]s using the
+    /// given connect `options` and the provided [`ManagerConfig`].
+    ///
+    /// [`Connection`]: crate::Connection
+    #[must_use]
+    pub fn from_config(options: C::Options, config: ManagerConfig) -> Self {
+        Self { options, config }
+    }
+
+    /// Returns the connect options of this [`Manager`].
+    #[must_use]
+    pub fn options(&self) -> &C::Options {
+        &self.options
+    }
+}
+
+impl<C> managed::Manager for Manager<C>
+where
+    C: Connection + 'static,
+{
+    type Type = C;
+    type Error = sqlx::Error;
+
+    async fn create(&self) -> Result<C, sqlx::Error> {
+        self.options.connect().await
+    }
+
+    async fn recycle(&self, conn: &mut C, _: &Metrics) -> RecycleResult<sqlx::Error> {
+        match self.config.recycling_method {
+            RecyclingMethod::Fast => Ok(()),
+            RecyclingMethod::Verified => Ok(conn.ping().await?),
+        }
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing objects from
+/// the [`Pool`].
+///
+/// [`Pool`]: crate::Pool
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which matches the `test_before_acquire`
+/// default of the sqlx pool.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Reuse connections without checking them.
+    ///
+    /// Connections which were closed by the server are only detected when
+    /// they are used.
+    Fast,
+
+    /// Run [`Connection::ping()`] when recycling existing connections.
+    #[default]
+    Verified,
+}
@@ -0,0 +1,18 @@
+//! Type aliases for using `deadpool-sqlx` with MySQL.
+
+pub use sqlx::mysql::{MySqlConnectOptions, MySqlConnection};
+
+/// Manager for MySQL connections
+pub type Manager = crate::Manager<MySqlConnection>;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "sqlx",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    sqlx::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
@@ -0,0 +1,18 @@
+//! Type aliases for using `deadpool-sqlx` with PostgreSQL.
+
+pub use sqlx::postgres::{PgConnectOptions, PgConnection};
+
+/// Manager for PostgreSQL connections
+pub type Manager = crate::Manager<PgConnection>;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "sqlx",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    sqlx::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
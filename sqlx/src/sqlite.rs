@@ -0,0 +1,18 @@
+//! Type aliases for using `deadpool-sqlx` with SQLite.
+
+pub use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection};
+
+/// Manager for SQLite connections
+pub type Manager = crate::Manager<SqliteConnection>;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "sqlx",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    sqlx::Error,
+    crate::ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
@@ -0,0 +1,250 @@
+use std::{fmt, path::PathBuf, time::Duration};
+
+use russh::keys::{load_secret_key, PrivateKey};
+
+use crate::{
+    CreatePoolError, KeyedPool, Manager, Pool, PoolBuilder, PoolConfig, Runtime, Target,
+    DEFAULT_PORT,
+};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// SSH__HOST=build.example.com
+/// SSH__USER=deploy
+/// SSH__PRIVATE_KEY_FILE=/etc/deploy/id_ed25519
+/// SSH__KEEPALIVE_INTERVAL__SECS=30
+/// SSH__KEEPALIVE_INTERVAL__NANOS=0
+/// SSH__POOL__MAX_SIZE=4
+/// SSH__POOL__TIMEOUTS__WAIT__SECS=5
+/// SSH__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     ssh: deadpool_ssh::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Host name or IP address of the server.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] takes the
+    /// host from the [`Target`] instead.
+    pub host: Option<String>,
+
+    /// Port of the server. Defaults to [`DEFAULT_PORT`].
+    pub port: Option<u16>,
+
+    /// User to log in as.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] takes the
+    /// user from the [`Target`] instead.
+    pub user: Option<String>,
+
+    /// Password used for password authentication.
+    pub password: Option<String>,
+
+    /// Path to a private key in OpenSSH or PKCS#8 format used for public
+    /// key authentication.
+    pub private_key_file: Option<PathBuf>,
+
+    /// Passphrase of the encrypted [`Config::private_key_file`].
+    pub private_key_passphrase: Option<String>,
+
+    /// Authenticate using the identities of the SSH agent reachable via
+    /// `SSH_AUTH_SOCK`.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub agent: bool,
+
+    /// Path to the `known_hosts` file used to verify the host keys.
+    ///
+    /// Defaults to `~/.ssh/known_hosts`.
+    pub known_hosts_file: Option<PathBuf>,
+
+    /// Accept host keys which are not listed in the `known_hosts` file.
+    /// Changed host keys are always rejected.
+    ///
+    /// **Important:** This makes the first connection to every host
+    /// vulnerable to man-in-the-middle attacks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub accept_unknown_host_keys: bool,
+
+    /// Timeout for establishing the connection including the key exchange.
+    pub connect_timeout: Option<Duration>,
+
+    /// Interval of keepalive requests sent while a session is idle.
+    ///
+    /// This keeps sessions of the pool from being closed by firewalls and
+    /// servers with an idle timeout.
+    pub keepalive_interval: Option<Duration>,
+
+    /// Number of unanswered keepalive requests after which a session is
+    /// closed. Defaults to `3`.
+    pub keepalive_max: Option<usize>,
+
+    /// [`Pool`] configuration used for every [`Target`].
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `host` and `user`.
+    #[must_use]
+    pub fn new<H: Into<String>, U: Into<String>>(host: H, user: U) -> Self {
+        Self {
+            host: Some(host.into()),
+            user: Some(user.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] for [`Config::get_target()`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let target = self.get_target().map_err(CreatePoolError::Config)?;
+        self.create_target_pool(target, runtime)
+    }
+
+    /// Creates a new [`PoolBuilder`] for [`Config::get_target()`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        self.target_builder(self.get_target()?)
+    }
+
+    /// Creates a new [`KeyedPool`] which creates one [`Pool`] per
+    /// [`Target`] using this [`Config`].
+    #[must_use]
+    pub fn create_keyed_pool(&self, runtime: Option<Runtime>) -> KeyedPool {
+        KeyedPool::new(self.clone(), runtime)
+    }
+
+    pub(crate) fn create_target_pool(
+        &self,
+        target: Target,
+        runtime: Option<Runtime>,
+    ) -> Result<Pool, CreatePoolError> {
+        let mut builder = self
+            .target_builder(target)
+            .map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    fn target_builder(&self, target: Target) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(target, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the [`Target`] configured by [`Config::host`],
+    /// [`Config::port`] and [`Config::user`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::MissingHost`] or [`ConfigError::MissingUser`]
+    /// if the host or the user is not set.
+    pub fn get_target(&self) -> Result<Target, ConfigError> {
+        let host = self.host.as_deref().ok_or(ConfigError::MissingHost)?;
+        let user = self.user.as_deref().ok_or(ConfigError::MissingUser)?;
+        Ok(Target::new(host, self.port.unwrap_or(DEFAULT_PORT), user))
+    }
+
+    /// Returns a [`russh::client::Config`] which can be used to connect to
+    /// the server.
+    #[must_use]
+    pub fn get_client_config(&self) -> russh::client::Config {
+        let mut config = russh::client::Config {
+            keepalive_interval: self.keepalive_interval,
+            nodelay: true,
+            ..Default::default()
+        };
+        if let Some(keepalive_max) = self.keepalive_max {
+            config.keepalive_max = keepalive_max;
+        }
+        config
+    }
+
+    /// Loads the configured [`Config::private_key_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::PrivateKey`] if the key could not be read or
+    /// decrypted.
+    pub fn get_private_key(&self) -> Result<Option<PrivateKey>, ConfigError> {
+        self.private_key_file
+            .as_ref()
+            .map(|path| load_secret_key(path, self.private_key_passphrase.as_deref()))
+            .transpose()
+            .map_err(ConfigError::PrivateKey)
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the SSH
+/// configuration.
+#[derive(Debug)]
+#[allow(missing_copy_implementations)] // `PrivateKey` variant is not `Copy`
+pub enum ConfigError {
+    /// No [`Config::host`] was specified.
+    MissingHost,
+
+    /// No [`Config::user`] was specified.
+    MissingUser,
+
+    /// Neither a password, a private key nor the SSH agent is configured.
+    MissingCredentials,
+
+    /// The [`Config::private_key_file`] could not be loaded.
+    PrivateKey(russh::keys::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHost => write!(f, "No host specified"),
+            Self::MissingUser => write!(f, "No user specified"),
+            Self::MissingCredentials => write!(f, "No authentication method configured"),
+            Self::PrivateKey(e) => write!(f, "Failed to load private key: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PrivateKey(e) => Some(e),
+            _ => None,
+        }
+    }
+}
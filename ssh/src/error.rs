@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Possible errors returned by the [`Manager`](crate::Manager).
+#[derive(Debug)]
+pub enum Error {
+    /// Connecting, verifying the host key or running the SSH protocol
+    /// failed.
+    Ssh(russh::Error),
+
+    /// The SSH agent could not be reached or failed to sign the
+    /// authentication request.
+    Agent(russh::keys::Error),
+
+    /// The server rejected every configured authentication method.
+    AuthenticationFailed,
+
+    /// Starting the SFTP subsystem or an SFTP request failed.
+    #[cfg(feature = "sftp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sftp")))]
+    Sftp(russh_sftp::client::error::Error),
+}
+
+impl From<russh::Error> for Error {
+    fn from(e: russh::Error) -> Self {
+        Self::Ssh(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ssh(e) => write!(f, "SSH error: {e}"),
+            Self::Agent(e) => write!(f, "SSH agent error: {e}"),
+            Self::AuthenticationFailed => write!(f, "Authentication failed"),
+            #[cfg(feature = "sftp")]
+            Self::Sftp(e) => write!(f, "SFTP error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Ssh(e) => Some(e),
+            Self::Agent(e) => Some(e),
+            Self::AuthenticationFailed => None,
+            #[cfg(feature = "sftp")]
+            Self::Sftp(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use deadpool_keyed::{PoolMap, DEFAULT_IDLE_TIMEOUT};
+
+use crate::{
+    CommandOutput, Config, CreatePoolError, Manager, Object, Pool, PoolError, Runtime, Target,
+};
+
+/// Pools of [`Session`](crate::Session)s keyed by their [`Target`].
+///
+/// The [`Pool`] of a [`Target`] is created on first use with the same
+/// [`Config`] for every target. Pools which are idle for the idle timeout
+/// are evicted, see [`PoolMap`] for details.
+#[derive(Clone, Debug)]
+pub struct KeyedPool {
+    inner: Arc<KeyedPoolInner>,
+}
+
+struct KeyedPoolInner {
+    config: Config,
+    runtime: Option<Runtime>,
+    pools: PoolMap<Target, Manager>,
+}
+
+// Implemented manually to not leak the password and passphrase of the config.
+impl fmt::Debug for KeyedPoolInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyedPoolInner")
+            .field("runtime", &self.runtime)
+            .field("pools", &self.pools)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyedPool {
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the [`DEFAULT_IDLE_TIMEOUT`] of 5 minutes.
+    #[must_use]
+    pub fn new(config: Config, runtime: Option<Runtime>) -> Self {
+        Self::with_idle_timeout(config, runtime, Some(DEFAULT_IDLE_TIMEOUT))
+    }
+
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the given `idle_timeout`. Pools are never evicted
+    /// if it is [`None`].
+    #[must_use]
+    pub fn with_idle_timeout(
+        config: Config,
+        runtime: Option<Runtime>,
+        idle_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(KeyedPoolInner {
+                config,
+                runtime,
+                pools: PoolMap::new(idle_timeout),
+            }),
+        }
+    }
+
+    /// Returns the [`Pool`] for the given [`Target`] and creates it if
+    /// necessary.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn pool(&self, target: &Target) -> Result<Pool, CreatePoolError> {
+        self.inner.pools.get_or_try_insert_with(target, || {
+            self.inner
+                .config
+                .create_target_pool(target.clone(), self.inner.runtime)
+        })
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the given [`Target`].
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn get(&self, target: &Target) -> Result<Object, KeyedPoolError> {
+        let pool = self.pool(target).map_err(KeyedPoolError::Create)?;
+        pool.get().await.map_err(KeyedPoolError::Pool)
+    }
+
+    /// Runs the given `command` using a session to the given [`Target`].
+    /// See [`Session::exec()`](crate::Session::exec).
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn exec(
+        &self,
+        target: &Target,
+        command: &str,
+    ) -> Result<CommandOutput, KeyedPoolError> {
+        let session = self.get(target).await?;
+        session.exec(command).await.map_err(KeyedPoolError::Exec)
+    }
+
+    /// Removes the [`Pool`] of the given [`Target`] and closes it.
+    pub fn remove(&self, target: &Target) {
+        if let Some(pool) = self.inner.pools.remove(target) {
+            pool.close();
+        }
+    }
+
+    /// Removes the pools which are idle. See [`PoolMap::evict_idle()`].
+    pub fn evict_idle(&self) {
+        self.inner.pools.evict_idle();
+    }
+
+    /// Returns the targets of all pools which were not evicted.
+    #[must_use]
+    pub fn targets(&self) -> Vec<Target> {
+        self.inner.pools.keys()
+    }
+}
+
+/// Possible errors returned by a [`KeyedPool`].
+#[derive(Debug)]
+pub enum KeyedPoolError {
+    /// The [`Pool`] for the [`Target`] could not be created.
+    Create(CreatePoolError),
+
+    /// No [`Object`] could be retrieved from the [`Pool`].
+    Pool(PoolError),
+
+    /// Running the command failed.
+    Exec(russh::Error),
+}
+
+impl fmt::Display for KeyedPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create(e) => write!(f, "Failed to create pool: {e}"),
+            Self::Pool(e) => write!(f, "Failed to get session: {e}"),
+            Self::Exec(e) => write!(f, "Failed to run command: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for KeyedPoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Create(e) => Some(e),
+            Self::Pool(e) => Some(e),
+            Self::Exec(e) => Some(e),
+        }
+    }
+}
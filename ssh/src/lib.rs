@@ -0,0 +1,196 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod error;
+mod keyed;
+mod session;
+
+use std::{fmt, path::PathBuf, sync::Arc, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use russh::{
+    client::{self, Handle},
+    keys::{PrivateKey, PrivateKeyWithHashAlg},
+};
+use tokio::time::timeout;
+
+pub use russh;
+
+pub use self::{
+    config::{Config, ConfigError},
+    error::Error,
+    keyed::{KeyedPool, KeyedPoolError},
+    session::{ClientHandler, CommandOutput, Session},
+};
+pub use deadpool_keyed::Target;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "russh",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Default port of SSH servers.
+pub const DEFAULT_PORT: u16 = 22;
+
+/// [`Manager`] for creating and recycling [`Session`]s to a single
+/// [`Target`].
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    target: Target,
+    client_config: Arc<client::Config>,
+    password: Option<String>,
+    private_key: Option<Arc<PrivateKey>>,
+    #[cfg(unix)]
+    agent: bool,
+    known_hosts_file: Option<PathBuf>,
+    accept_unknown_host_keys: bool,
+    connect_timeout: Option<Duration>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the given [`Target`] using the given
+    /// [`Config`].
+    ///
+    /// The private key is loaded right away so an invalid key file is
+    /// reported here rather than on every connection attempt.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(target: Target, config: &Config) -> Result<Self, ConfigError> {
+        let private_key = config.get_private_key()?.map(Arc::new);
+        #[cfg(unix)]
+        let agent = config.agent;
+        #[cfg(not(unix))]
+        let agent = false;
+        if !agent && private_key.is_none() && config.password.is_none() {
+            return Err(ConfigError::MissingCredentials);
+        }
+        Ok(Self {
+            target,
+            client_config: Arc::new(config.get_client_config()),
+            password: config.password.clone(),
+            private_key,
+            #[cfg(unix)]
+            agent,
+            known_hosts_file: config.known_hosts_file.clone(),
+            accept_unknown_host_keys: config.accept_unknown_host_keys,
+            connect_timeout: config.connect_timeout,
+        })
+    }
+
+    /// Returns the [`Target`] of this [`Manager`].
+    #[must_use]
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Tries the SSH agent, the private key and the password in this order
+    /// until the server accepts one of them.
+    async fn authenticate(&self, handle: &mut Handle<ClientHandler>) -> Result<(), Error> {
+        let user = self.target.user();
+        #[cfg(unix)]
+        if self.agent && self.authenticate_agent(handle).await? {
+            return Ok(());
+        }
+        if let Some(key) = &self.private_key {
+            let hash_alg = handle.best_supported_rsa_hash().await?.flatten();
+            let key = PrivateKeyWithHashAlg::new(key.clone(), hash_alg);
+            if handle.authenticate_publickey(user, key).await?.success() {
+                return Ok(());
+            }
+        }
+        if let Some(password) = &self.password {
+            if handle
+                .authenticate_password(user, password)
+                .await?
+                .success()
+            {
+                return Ok(());
+            }
+        }
+        Err(Error::AuthenticationFailed)
+    }
+
+    #[cfg(unix)]
+    async fn authenticate_agent(&self, handle: &mut Handle<ClientHandler>) -> Result<bool, Error> {
+        use russh::{keys::agent::client::AgentClient, AgentAuthError};
+
+        let mut agent = AgentClient::connect_env().await.map_err(Error::Agent)?;
+        let identities = agent.request_identities().await.map_err(Error::Agent)?;
+        for key in identities {
+            let hash_alg = handle.best_supported_rsa_hash().await?.flatten();
+            let result = handle
+                .authenticate_publickey_with(self.target.user(), key, hash_alg, &mut agent)
+                .await
+                .map_err(|e| match e {
+                    AgentAuthError::Send(_) => Error::Ssh(russh::Error::SendError),
+                    AgentAuthError::Key(e) => Error::Agent(e),
+                })?;
+            if result.success() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+// Implemented manually to not leak the credentials.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("target", &self.target)
+            .field("known_hosts_file", &self.known_hosts_file)
+            .field("accept_unknown_host_keys", &self.accept_unknown_host_keys)
+            .field("connect_timeout", &self.connect_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Session;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Session, Error> {
+        let handler = ClientHandler::new(
+            &self.target,
+            self.known_hosts_file.clone(),
+            self.accept_unknown_host_keys,
+        );
+        let connect = client::connect(
+            self.client_config.clone(),
+            (self.target.host(), self.target.port()),
+            handler,
+        );
+        let mut handle = match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, connect)
+                .await
+                .map_err(|_| russh::Error::ConnectionTimeout)??,
+            None => connect.await?,
+        };
+        self.authenticate(&mut handle).await?;
+        Ok(Session::new(self.target.clone(), handle))
+    }
+
+    async fn recycle(&self, session: &mut Session, _: &Metrics) -> RecycleResult {
+        if session.is_closed() {
+            return Err(RecycleError::message("Session closed"));
+        }
+        // Waits for the server to answer a `keepalive@openssh.com` request.
+        session.send_ping().await.map_err(Error::Ssh)?;
+        if session.is_closed() {
+            return Err(RecycleError::message("Session closed"));
+        }
+        #[cfg(feature = "sftp")]
+        session.ping_sftp().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+};
+
+use russh::{
+    client::{self, Handle},
+    keys::{check_known_hosts, check_known_hosts_path, PublicKey},
+    ChannelMsg,
+};
+
+use crate::{Error, Target};
+
+/// Wrapper around a [`russh::client::Handle`] of an authenticated session.
+pub struct Session {
+    target: Target,
+    handle: Handle<ClientHandler>,
+    #[cfg(feature = "sftp")]
+    sftp: Option<russh_sftp::client::SftpSession>,
+}
+
+impl Session {
+    pub(crate) fn new(target: Target, handle: Handle<ClientHandler>) -> Self {
+        Self {
+            target,
+            handle,
+            #[cfg(feature = "sftp")]
+            sftp: None,
+        }
+    }
+
+    /// Returns the [`Target`] of this [`Session`].
+    #[must_use]
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Runs the given `command` on a new channel and waits until it exits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel could not be opened or the command
+    /// could not be started. A command that exits with a non-zero status is
+    /// not an error, see [`CommandOutput::success()`].
+    pub async fn exec(&self, command: &str) -> Result<CommandOutput, russh::Error> {
+        let mut channel = self.handle.channel_open_session().await?;
+        channel.exec(true, command).await?;
+        let mut output = CommandOutput::default();
+        while let Some(msg) = channel.wait().await {
+            match msg {
+                ChannelMsg::Data { data } => output.stdout.extend_from_slice(&data),
+                ChannelMsg::ExtendedData { data, ext: 1 } => {
+                    output.stderr.extend_from_slice(&data);
+                }
+                ChannelMsg::ExitStatus { exit_status } => output.exit_status = Some(exit_status),
+                _ => {}
+            }
+        }
+        Ok(output)
+    }
+
+    /// Returns the SFTP session of this [`Session`] and starts the SFTP
+    /// subsystem on first use.
+    ///
+    /// The SFTP session stays open while the [`Session`] is pooled, so
+    /// later users of the [`Session`] don't need to start it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel could not be opened or the server
+    /// doesn't support SFTP.
+    #[cfg(feature = "sftp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sftp")))]
+    pub async fn sftp(&mut self) -> Result<&russh_sftp::client::SftpSession, Error> {
+        let sftp = match self.sftp.take() {
+            Some(sftp) => sftp,
+            None => {
+                let channel = self.handle.channel_open_session().await?;
+                channel.request_subsystem(true, "sftp").await?;
+                russh_sftp::client::SftpSession::new(channel.into_stream())
+                    .await
+                    .map_err(Error::Sftp)?
+            }
+        };
+        Ok(self.sftp.insert(sftp))
+    }
+
+    /// Checks that the server still answers requests of the SFTP session
+    /// if it was started.
+    #[cfg(feature = "sftp")]
+    pub(crate) async fn ping_sftp(&self) -> Result<(), Error> {
+        if let Some(sftp) = &self.sftp {
+            let _ = sftp.canonicalize(".").await.map_err(Error::Sftp)?;
+        }
+        Ok(())
+    }
+}
+
+// Implemented manually as `Handle` doesn't implement `Debug`.
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("target", &self.target)
+            .field("closed", &self.handle.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for Session {
+    type Target = Handle<ClientHandler>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl DerefMut for Session {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.handle
+    }
+}
+
+/// Output of a command run by [`Session::exec()`].
+#[derive(Clone, Debug, Default)]
+pub struct CommandOutput {
+    /// Data written to `stdout`.
+    pub stdout: Vec<u8>,
+
+    /// Data written to `stderr`.
+    pub stderr: Vec<u8>,
+
+    /// Exit status of the command. This is [`None`] if the server didn't
+    /// report one, e.g. because the command was killed by a signal.
+    pub exit_status: Option<u32>,
+}
+
+impl CommandOutput {
+    /// Returns `true` if the command exited with status `0`.
+    #[must_use]
+    pub fn success(&self) -> bool {
+        self.exit_status == Some(0)
+    }
+}
+
+/// [`russh::client::Handler`] of the sessions created by this crate.
+///
+/// It verifies the host key of the server against the `known_hosts` file.
+#[derive(Debug)]
+pub struct ClientHandler {
+    host: String,
+    port: u16,
+    known_hosts_file: Option<PathBuf>,
+    accept_unknown_host_keys: bool,
+}
+
+impl ClientHandler {
+    pub(crate) fn new(
+        target: &Target,
+        known_hosts_file: Option<PathBuf>,
+        accept_unknown_host_keys: bool,
+    ) -> Self {
+        Self {
+            host: target.host().to_owned(),
+            port: target.port(),
+            known_hosts_file,
+            accept_unknown_host_keys,
+        }
+    }
+}
+
+impl client::Handler for ClientHandler {
+    type Error = Error;
+
+    async fn check_server_key(&mut self, server_public_key: &PublicKey) -> Result<bool, Error> {
+        let known = match &self.known_hosts_file {
+            Some(path) => check_known_hosts_path(&self.host, self.port, server_public_key, path),
+            None => check_known_hosts(&self.host, self.port, server_public_key),
+        };
+        match known {
+            Ok(true) => Ok(true),
+            Ok(false) => Ok(self.accept_unknown_host_keys),
+            // Without a home directory there is no default `known_hosts`
+            // file, so no host key is known. A missing file is handled the
+            // same way by russh.
+            Err(russh::keys::Error::NoHomeDir) => Ok(self.accept_unknown_host_keys),
+            // This includes `KeyChanged` which is never accepted.
+            Err(e) => Err(Error::Ssh(e.into())),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::{collections::HashMap, fmt, time::Duration};
+
+#[cfg(feature = "rustls")]
+use crate::TlsConfig;
+use crate::{CreatePoolError, KeyedPool, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// WEBSOCKET__URL=wss://stream.example.com/v1/events
+/// WEBSOCKET__HEADERS__AUTHORIZATION=Bearer topsecret
+/// WEBSOCKET__PING_TIMEOUT__SECS=5
+/// WEBSOCKET__PING_TIMEOUT__NANOS=0
+/// WEBSOCKET__POOL__MAX_SIZE=8
+/// WEBSOCKET__POOL__TIMEOUTS__WAIT__SECS=5
+/// WEBSOCKET__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     websocket: deadpool_websocket::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URL of the WebSocket endpoint, e.g. `wss://example.com/socket`.
+    ///
+    /// Only used by [`Config::create_pool()`]. A [`KeyedPool`] takes the
+    /// URL passed to it instead.
+    pub url: Option<String>,
+
+    /// Additional headers sent with the opening handshake, e.g.
+    /// `Authorization`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub headers: HashMap<String, String>,
+
+    /// Subprotocols offered in the `Sec-WebSocket-Protocol` header.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub protocols: Vec<String>,
+
+    /// Timeout for establishing the connection including the TLS and the
+    /// WebSocket handshake.
+    pub connect_timeout: Option<Duration>,
+
+    /// Timeout for receiving the pong to the ping which is sent before a
+    /// connection is reused. Defaults to 5 seconds.
+    pub ping_timeout: Option<Duration>,
+
+    /// Maximum number of text and binary messages which are kept when they
+    /// are received while waiting for the pong. Reading from the
+    /// [`Connection`](crate::Connection) returns them first.
+    ///
+    /// A connection receiving more messages is discarded. Defaults to `0`
+    /// which discards connections receiving any message as it might be a
+    /// late response meant for the previous user. Increase it for servers
+    /// which push messages by themselves.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_unread_messages: usize,
+
+    /// Maximum size of an incoming message. Defaults to the tungstenite
+    /// default of 64 MiB.
+    pub max_message_size: Option<usize>,
+
+    /// TLS configuration used for `wss` URLs.
+    ///
+    /// The Mozilla root certificates are used if this is not set.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub tls: Option<TlsConfig>,
+
+    /// [`Pool`] configuration used for every URL.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] for the given `url`.
+    #[must_use]
+    pub fn from_url<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] for the [`Config::url`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let url = self.url.as_deref().ok_or(ConfigError::MissingUrl);
+        let url = url.map_err(CreatePoolError::Config)?;
+        self.create_url_pool(url, runtime)
+    }
+
+    /// Creates a new [`PoolBuilder`] for the [`Config::url`] using this
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        self.url_builder(self.url.as_deref().ok_or(ConfigError::MissingUrl)?)
+    }
+
+    /// Creates a new [`KeyedPool`] which creates one [`Pool`] per URL
+    /// using this [`Config`].
+    #[must_use]
+    pub fn create_keyed_pool(&self, runtime: Option<Runtime>) -> KeyedPool {
+        KeyedPool::new(self.clone(), runtime)
+    }
+
+    pub(crate) fn create_url_pool(
+        &self,
+        url: &str,
+        runtime: Option<Runtime>,
+    ) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.url_builder(url).map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    fn url_builder(&self, url: &str) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::for_url(url, self)?;
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the WebSocket
+/// configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// No [`Config::url`] was specified.
+    MissingUrl,
+
+    /// The URL could not be parsed or has no host.
+    InvalidUrl(String),
+
+    /// The scheme of the URL is neither `ws` nor `wss`.
+    UnsupportedScheme(String),
+
+    /// A `wss` URL was used without enabling the `rustls` feature.
+    #[cfg(not(feature = "rustls"))]
+    WssNotSupported,
+
+    /// An entry of [`Config::headers`] or [`Config::protocols`] is not a
+    /// valid header.
+    InvalidHeader(String),
+
+    /// The TLS configuration is invalid.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    Tls(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingUrl => write!(f, "No URL specified"),
+            Self::InvalidUrl(url) => write!(f, "Invalid URL `{url}`"),
+            Self::UnsupportedScheme(scheme) => {
+                write!(f, "Unsupported URL scheme `{scheme}`, expected ws or wss")
+            }
+            #[cfg(not(feature = "rustls"))]
+            Self::WssNotSupported => write!(f, "wss URLs require the `rustls` feature"),
+            Self::InvalidHeader(name) => write!(f, "Invalid header `{name}`"),
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => write!(f, "Invalid TLS configuration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "rustls")]
+            Self::Tls(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
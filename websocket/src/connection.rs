@@ -0,0 +1,95 @@
+use std::{
+    collections::VecDeque,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{Sink, Stream, StreamExt};
+use tokio_tungstenite::tungstenite::{Error, Message};
+
+use crate::WebSocket;
+
+/// [`WebSocket`] connection which keeps the messages received while it was
+/// recycled.
+///
+/// Reading from a [`Connection`] returns these messages first, followed by
+/// the messages read from the [`WebSocket`]. See
+/// [`Config::max_unread_messages`](crate::Config::max_unread_messages).
+#[derive(Debug)]
+pub struct Connection {
+    websocket: WebSocket,
+    unread: VecDeque<Message>,
+}
+
+impl Connection {
+    pub(crate) fn new(websocket: WebSocket) -> Self {
+        Self {
+            websocket,
+            unread: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of messages received while this [`Connection`] was
+    /// recycled which were not read yet.
+    #[must_use]
+    pub fn unread(&self) -> usize {
+        self.unread.len()
+    }
+
+    /// Unwraps the [`WebSocket`] and the messages which were not read yet.
+    #[must_use]
+    pub fn into_inner(self) -> (WebSocket, VecDeque<Message>) {
+        (self.websocket, self.unread)
+    }
+
+    pub(crate) fn push_unread(&mut self, message: Message) {
+        self.unread.push_back(message);
+    }
+}
+
+impl Deref for Connection {
+    type Target = WebSocket;
+
+    fn deref(&self) -> &WebSocket {
+        &self.websocket
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut WebSocket {
+        &mut self.websocket
+    }
+}
+
+impl Stream for Connection {
+    type Item = Result<Message, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(message) = this.unread.pop_front() {
+            return Poll::Ready(Some(Ok(message)));
+        }
+        this.websocket.poll_next_unpin(cx)
+    }
+}
+
+impl Sink<Message> for Connection {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().websocket).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Message) -> Result<(), Error> {
+        Pin::new(&mut self.get_mut().websocket).start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().websocket).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.get_mut().websocket).poll_close(cx)
+    }
+}
@@ -0,0 +1,126 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use deadpool_keyed::{PoolMap, DEFAULT_IDLE_TIMEOUT};
+
+use crate::{Config, CreatePoolError, Manager, Object, Pool, PoolError, Runtime};
+
+/// Pools of [`Connection`](crate::Connection)s keyed by their URL.
+///
+/// The [`Pool`] of a URL is created on first use with the same [`Config`]
+/// for every URL. Pools which are idle for the idle timeout are evicted,
+/// see [`PoolMap`] for details.
+#[derive(Clone, Debug)]
+pub struct KeyedPool {
+    inner: Arc<KeyedPoolInner>,
+}
+
+struct KeyedPoolInner {
+    config: Config,
+    runtime: Option<Runtime>,
+    pools: PoolMap<String, Manager>,
+}
+
+// Implemented manually to not leak credentials sent in the headers.
+impl fmt::Debug for KeyedPoolInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyedPoolInner")
+            .field("runtime", &self.runtime)
+            .field("pools", &self.pools)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyedPool {
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the [`DEFAULT_IDLE_TIMEOUT`] of 5 minutes.
+    #[must_use]
+    pub fn new(config: Config, runtime: Option<Runtime>) -> Self {
+        Self::with_idle_timeout(config, runtime, Some(DEFAULT_IDLE_TIMEOUT))
+    }
+
+    /// Creates a new empty [`KeyedPool`] using the given [`Config`] which
+    /// evicts pools after the given `idle_timeout`. Pools are never evicted
+    /// if it is [`None`].
+    #[must_use]
+    pub fn with_idle_timeout(
+        config: Config,
+        runtime: Option<Runtime>,
+        idle_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(KeyedPoolInner {
+                config,
+                runtime,
+                pools: PoolMap::new(idle_timeout),
+            }),
+        }
+    }
+
+    /// Returns the [`Pool`] for the given `url` and creates it if
+    /// necessary.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn pool(&self, url: &str) -> Result<Pool, CreatePoolError> {
+        self.inner.pools.get_or_try_insert_with(url, || {
+            self.inner.config.create_url_pool(url, self.inner.runtime)
+        })
+    }
+
+    /// Retrieves an [`Object`] from the [`Pool`] of the given `url`.
+    ///
+    /// # Errors
+    ///
+    /// See [`KeyedPoolError`] for details.
+    pub async fn get(&self, url: &str) -> Result<Object, KeyedPoolError> {
+        let pool = self.pool(url).map_err(KeyedPoolError::Create)?;
+        pool.get().await.map_err(KeyedPoolError::Pool)
+    }
+
+    /// Removes the [`Pool`] of the given `url` and closes it.
+    pub fn remove(&self, url: &str) {
+        if let Some(pool) = self.inner.pools.remove(url) {
+            pool.close();
+        }
+    }
+
+    /// Removes the pools which are idle. See [`PoolMap::evict_idle()`].
+    pub fn evict_idle(&self) {
+        self.inner.pools.evict_idle();
+    }
+
+    /// Returns the URLs of all pools which were not evicted.
+    #[must_use]
+    pub fn urls(&self) -> Vec<String> {
+        self.inner.pools.keys()
+    }
+}
+
+/// Possible errors returned by a [`KeyedPool`].
+#[derive(Debug)]
+pub enum KeyedPoolError {
+    /// The [`Pool`] for the URL could not be created.
+    Create(CreatePoolError),
+
+    /// No [`Object`] could be retrieved from the [`Pool`].
+    Pool(PoolError),
+}
+
+impl fmt::Display for KeyedPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create(e) => write!(f, "Failed to create pool: {e}"),
+            Self::Pool(e) => write!(f, "Failed to get connection: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for KeyedPoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Create(e) => Some(e),
+            Self::Pool(e) => Some(e),
+        }
+    }
+}
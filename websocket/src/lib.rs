@@ -0,0 +1,226 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod connection;
+mod keyed;
+#[cfg(feature = "rustls")]
+mod tls;
+
+use std::{fmt, io, time::Duration};
+
+use deadpool::managed::{self, RecycleError};
+use futures_util::{SinkExt, StreamExt};
+use tokio::{net::TcpStream, time::timeout};
+use tokio_tungstenite::{
+    tungstenite::{
+        client::IntoClientRequest,
+        http::{
+            header::{HeaderName, HeaderValue, SEC_WEBSOCKET_PROTOCOL},
+            HeaderMap, Uri,
+        },
+        protocol::WebSocketConfig,
+        Error, Message,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+
+pub use tokio_tungstenite;
+
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+pub use self::tls::TlsConfig;
+pub use self::{
+    config::{Config, ConfigError},
+    connection::Connection,
+    keyed::{KeyedPool, KeyedPoolError},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "tokio-tungstenite",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// Default timeout for receiving the pong when recycling a connection.
+const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// WebSocket connection managed by the [`Manager`].
+pub type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// [`Manager`] for creating and recycling [`WebSocket`] connections to a
+/// single URL.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    uri: Uri,
+    headers: HeaderMap,
+    connect_timeout: Option<Duration>,
+    ping_timeout: Duration,
+    max_unread_messages: usize,
+    websocket_config: WebSocketConfig,
+    #[cfg(feature = "rustls")]
+    tls: Option<std::sync::Arc<tokio_rustls::rustls::ClientConfig>>,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] for the [`Config::url`] using the given
+    /// [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        Self::for_url(
+            config.url.as_deref().ok_or(ConfigError::MissingUrl)?,
+            config,
+        )
+    }
+
+    /// Creates a new [`Manager`] for the given `url` which was either taken
+    /// from the [`Config::url`] or passed to a [`KeyedPool`].
+    pub(crate) fn for_url(url: &str, config: &Config) -> Result<Self, ConfigError> {
+        let uri = url
+            .parse::<Uri>()
+            .map_err(|_| ConfigError::InvalidUrl(url.to_owned()))?;
+        if uri.host().is_none() {
+            return Err(ConfigError::InvalidUrl(url.to_owned()));
+        }
+        let encrypted = match uri.scheme_str() {
+            Some("ws") => false,
+            Some("wss") => true,
+            scheme => {
+                return Err(ConfigError::UnsupportedScheme(
+                    scheme.unwrap_or_default().to_owned(),
+                ))
+            }
+        };
+        #[cfg(not(feature = "rustls"))]
+        if encrypted {
+            return Err(ConfigError::WssNotSupported);
+        }
+        let mut headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            let invalid = || ConfigError::InvalidHeader(name.clone());
+            let _ = headers.insert(
+                HeaderName::try_from(name).map_err(|_| invalid())?,
+                HeaderValue::try_from(value).map_err(|_| invalid())?,
+            );
+        }
+        if !config.protocols.is_empty() {
+            let protocols = HeaderValue::try_from(config.protocols.join(", "))
+                .map_err(|_| ConfigError::InvalidHeader(SEC_WEBSOCKET_PROTOCOL.to_string()))?;
+            let _ = headers.insert(SEC_WEBSOCKET_PROTOCOL, protocols);
+        }
+        let mut websocket_config = WebSocketConfig::default();
+        if let Some(max_message_size) = config.max_message_size {
+            websocket_config = websocket_config.max_message_size(Some(max_message_size));
+        }
+        Ok(Self {
+            #[cfg(feature = "rustls")]
+            tls: if encrypted {
+                let tls = config.tls.clone().unwrap_or_default();
+                Some(tls.client_config()?)
+            } else {
+                None
+            },
+            uri,
+            headers,
+            connect_timeout: config.connect_timeout,
+            ping_timeout: config.ping_timeout.unwrap_or(DEFAULT_PING_TIMEOUT),
+            max_unread_messages: config.max_unread_messages,
+            websocket_config,
+        })
+    }
+
+    /// Returns the URL of this [`Manager`].
+    #[must_use]
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    async fn connect(&self) -> Result<WebSocket, Error> {
+        let mut request = self.uri.clone().into_client_request()?;
+        request.headers_mut().extend(self.headers.clone());
+        #[cfg(feature = "rustls")]
+        let (websocket, _) = tokio_tungstenite::connect_async_tls_with_config(
+            request,
+            Some(self.websocket_config),
+            true,
+            self.tls.clone().map(tokio_tungstenite::Connector::Rustls),
+        )
+        .await?;
+        #[cfg(not(feature = "rustls"))]
+        let (websocket, _) = tokio_tungstenite::connect_async_with_config(
+            request,
+            Some(self.websocket_config),
+            true,
+        )
+        .await?;
+        Ok(websocket)
+    }
+
+    /// Sends a ping with the given `payload` and waits for the matching
+    /// pong.
+    ///
+    /// Text and binary messages received meanwhile are kept by the
+    /// [`Connection`] up to the [`Config::max_unread_messages`].
+    async fn ping(&self, conn: &mut Connection, payload: Vec<u8>) -> RecycleResult {
+        conn.send(Message::Ping(payload.clone().into())).await?;
+        while let Some(message) = (**conn).next().await {
+            match message? {
+                Message::Pong(data) if data[..] == payload[..] => return Ok(()),
+                // Pings of the server are answered by tungstenite.
+                Message::Ping(_) | Message::Pong(_) => {}
+                Message::Close(_) => break,
+                message @ (Message::Text(_) | Message::Binary(_))
+                    if conn.unread() < self.max_unread_messages =>
+                {
+                    conn.push_unread(message);
+                }
+                _ => return Err(RecycleError::message("Unread message")),
+            }
+        }
+        Err(RecycleError::message("Connection closed"))
+    }
+}
+
+// Implemented manually to not leak credentials sent in the headers.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("uri", &self.uri)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("ping_timeout", &self.ping_timeout)
+            .field("max_unread_messages", &self.max_unread_messages)
+            .field("websocket_config", &self.websocket_config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection, Error> {
+        let websocket = match self.connect_timeout {
+            Some(connect_timeout) => timeout(connect_timeout, self.connect())
+                .await
+                .map_err(|_| Error::Io(io::ErrorKind::TimedOut.into()))??,
+            None => self.connect().await?,
+        };
+        Ok(Connection::new(websocket))
+    }
+
+    async fn recycle(&self, conn: &mut Connection, metrics: &Metrics) -> RecycleResult {
+        let payload = metrics.recycle_count.to_be_bytes().to_vec();
+        timeout(self.ping_timeout, self.ping(conn, payload))
+            .await
+            .map_err(|_| RecycleError::message("Ping timed out"))?
+    }
+}
@@ -0,0 +1,23 @@
+use std::{path::PathBuf, sync::Arc};
+
+use tokio_rustls::rustls::ClientConfig;
+
+use crate::ConfigError;
+
+/// TLS configuration used for `wss` URLs.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TlsConfig {
+    /// PEM file containing the CA certificates used to verify the servers.
+    ///
+    /// Defaults to the Mozilla root certificates.
+    pub ca_file: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    pub(crate) fn client_config(&self) -> Result<Arc<ClientConfig>, ConfigError> {
+        let config = deadpool_rustls::client_config(self.ca_file.as_deref())
+            .map_err(|e| ConfigError::Tls(e.into()))?;
+        Ok(Arc::new(config))
+    }
+}
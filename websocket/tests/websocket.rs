@@ -0,0 +1,61 @@
+//! End to end tests against a local WebSocket server.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use deadpool_websocket::{tokio_tungstenite::tungstenite::Message, Config, Pool, Runtime};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+
+/// Starts a server which sends `push` after accepting a connection and
+/// then answers pings until the connection is closed.
+async fn server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counter = connections.clone();
+    drop(tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let _ = counter.fetch_add(1, Ordering::Relaxed);
+            drop(tokio::spawn(async move {
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                websocket.send(Message::text("push")).await.unwrap();
+                while let Some(Ok(_)) = websocket.next().await {}
+            }));
+        }
+    }));
+    (url, connections)
+}
+
+async fn pool(max_unread_messages: usize) -> (Pool, Arc<AtomicUsize>) {
+    let (url, connections) = server().await;
+    let mut cfg = Config::from_url(url);
+    cfg.max_unread_messages = max_unread_messages;
+    (cfg.create_pool(Some(Runtime::Tokio1)).unwrap(), connections)
+}
+
+#[tokio::test]
+async fn unread_message_discards_connection() {
+    let (pool, connections) = pool(0).await;
+    drop(pool.get().await.unwrap());
+    let mut conn = pool.get().await.unwrap();
+    assert_eq!(connections.load(Ordering::Relaxed), 2);
+    assert_eq!(conn.next().await.unwrap().unwrap(), Message::text("push"));
+}
+
+#[tokio::test]
+async fn unread_message_is_kept() {
+    let (pool, connections) = pool(1).await;
+    drop(pool.get().await.unwrap());
+    let mut conn = pool.get().await.unwrap();
+    assert_eq!(connections.load(Ordering::Relaxed), 1);
+    assert_eq!(conn.unread(), 1);
+    assert_eq!(conn.next().await.unwrap().unwrap(), Message::text("push"));
+    assert_eq!(conn.unread(), 0);
+    drop(conn);
+    let conn = pool.get().await.unwrap();
+    assert_eq!(connections.load(Ordering::Relaxed), 1);
+    assert_eq!(conn.unread(), 0);
+}
@@ -0,0 +1,160 @@
+use std::{fmt, time::Duration};
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime, Watch};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// ZOOKEEPER__CONNECT_STRING=zk1:2181,zk2:2181,zk3:2181/app
+/// ZOOKEEPER__SESSION_TIMEOUT__SECS=10
+/// ZOOKEEPER__SESSION_TIMEOUT__NANOS=0
+/// ZOOKEEPER__POOL__MAX_SIZE=4
+/// ZOOKEEPER__POOL__TIMEOUTS__WAIT__SECS=5
+/// ZOOKEEPER__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     zookeeper: deadpool_zookeeper::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         let cfg = config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?;
+///         cfg.try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// Connect string, e.g. `zk1:2181,zk2:2181,zk3:2181/chroot`.
+    pub connect_string: Option<String>,
+
+    /// Session timeout negotiated with the server. Defaults to 6 seconds.
+    pub session_timeout: Option<Duration>,
+
+    /// Idle timeout after which a connection is considered lost. Defaults to
+    /// 2/5 of the session timeout.
+    pub connection_timeout: Option<Duration>,
+
+    /// Allow sessions to read-only servers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub readonly: bool,
+
+    /// Fail to create a session once all servers have been tried instead of
+    /// retrying until the session timeout elapsed.
+    ///
+    /// Don't enable this if the cluster is reachable via a single virtual
+    /// IP.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fail_eagerly: bool,
+
+    /// Authentication scheme, e.g. `digest`.
+    pub auth_scheme: Option<String>,
+
+    /// Authentication data for the [`Config::auth_scheme`], e.g.
+    /// `user:password`.
+    pub auth: Option<String>,
+
+    /// Persistent watches registered on the sessions of the pool. See
+    /// [`Manager::subscribe()`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub watches: Vec<Watch>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] with the given connect string.
+    #[must_use]
+    pub fn from_connect_string<T: Into<String>>(connect_string: T) -> Self {
+        Self {
+            connect_string: Some(connect_string.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        self.builder(runtime)
+            .map_err(CreatePoolError::Config)?
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// The sessions and the tasks forwarding the events of the
+    /// [`Config::watches`] are always driven by the current tokio runtime,
+    /// so the [`Runtime`] of the [`Pool`] must be the tokio one, if any.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self, runtime: Option<Runtime>) -> Result<PoolBuilder, ConfigError> {
+        let builder = Pool::builder(Manager::from_config(self)?).config(self.get_pool_config());
+        match runtime {
+            #[cfg(feature = "rt_tokio_1")]
+            Some(runtime @ Runtime::Tokio1) => Ok(builder.runtime(runtime)),
+            None => Ok(builder),
+            #[allow(unreachable_patterns)]
+            Some(runtime) => Err(ConfigError::UnsupportedRuntime(runtime)),
+        }
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// This error is returned if there is something wrong with the ZooKeeper
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigError {
+    /// No [`Config::connect_string`] was specified.
+    MissingConnectString,
+
+    /// Only one of [`Config::auth_scheme`] and [`Config::auth`] was set.
+    IncompleteAuth,
+
+    /// The [`Runtime`] is not supported as the sessions are driven by
+    /// tokio.
+    UnsupportedRuntime(Runtime),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingConnectString => write!(f, "No connect string specified"),
+            Self::IncompleteAuth => {
+                write!(
+                    f,
+                    "Authentication scheme and data must be configured together"
+                )
+            }
+            Self::UnsupportedRuntime(runtime) => {
+                write!(
+                    f,
+                    "Unsupported runtime {runtime:?}, only tokio is supported"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
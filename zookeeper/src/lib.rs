@@ -0,0 +1,137 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod config;
+mod watch;
+
+use std::fmt;
+
+use deadpool::managed::{self, RecycleError};
+use tokio::sync::broadcast;
+use zookeeper_client::{Client, Connector, Error, SessionState, WatchedEvent};
+
+pub use zookeeper_client;
+
+pub use self::{
+    config::{Config, ConfigError},
+    watch::{Watch, WatchMode, WATCH_EVENT_CAPACITY},
+};
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "zookeeper_client",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+type RecycleResult = managed::RecycleResult<Error>;
+
+/// [`Manager`] for creating and recycling [`zookeeper_client::Client`]
+/// sessions.
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    connect_string: String,
+    config: Config,
+    watches: watch::Watches,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let connect_string = config
+            .connect_string
+            .clone()
+            .ok_or(ConfigError::MissingConnectString)?;
+        if config.auth_scheme.is_some() != config.auth.is_some() {
+            return Err(ConfigError::IncompleteAuth);
+        }
+        Ok(Self {
+            connect_string,
+            config: config.clone(),
+            watches: watch::Watches::new(config.watches.clone()),
+        })
+    }
+
+    /// Returns a receiver of the events of the configured
+    /// [`Config::watches`].
+    ///
+    /// The watches are registered on one session at a time, starting with
+    /// the first session of the pool. Once that session expires or is
+    /// closed a background task creates a new session and registers them
+    /// again, retrying every second until it succeeds. Besides node events the
+    /// receiver gets the state changes of the watching session. Rebuild any
+    /// state derived from the events after a [`SessionState::Disconnected`]
+    /// or a terminal state as events may be lost in between.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<WatchedEvent> {
+        self.watches.subscribe()
+    }
+
+    fn connector(&self) -> Connector {
+        let mut connector = Client::connector().with_readonly(self.config.readonly);
+        if let Some(session_timeout) = self.config.session_timeout {
+            connector = connector.with_session_timeout(session_timeout);
+        }
+        if let Some(connection_timeout) = self.config.connection_timeout {
+            connector = connector.with_connection_timeout(connection_timeout);
+        }
+        if let (Some(scheme), Some(auth)) = (&self.config.auth_scheme, &self.config.auth) {
+            connector = connector.with_auth(scheme, auth.as_bytes());
+        }
+        if self.config.fail_eagerly {
+            connector = connector.with_fail_eagerly();
+        }
+        connector
+    }
+}
+
+// Implemented manually to not leak the authentication data.
+impl fmt::Debug for Manager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Manager")
+            .field("connect_string", &self.connect_string)
+            .field("session_timeout", &self.config.session_timeout)
+            .field("connection_timeout", &self.config.connection_timeout)
+            .field("readonly", &self.config.readonly)
+            .field("fail_eagerly", &self.config.fail_eagerly)
+            .field("auth_scheme", &self.config.auth_scheme)
+            .field("watches", &self.watches)
+            .finish_non_exhaustive()
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Client;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Client, Error> {
+        let client = self.connector().connect(&self.connect_string).await?;
+        let reconnect = watch::Reconnect {
+            connector: self.connector(),
+            connect_string: self.connect_string.clone(),
+        };
+        self.watches.register(&client, reconnect).await?;
+        Ok(client)
+    }
+
+    async fn recycle(&self, client: &mut Client, _: &Metrics) -> RecycleResult {
+        match client.state() {
+            // The client reconnects on its own. The session and its
+            // ephemeral nodes survive as long as this succeeds within the
+            // session timeout.
+            SessionState::SyncConnected
+            | SessionState::ConnectedReadOnly
+            | SessionState::Disconnected => Ok(()),
+            SessionState::Expired => Err(RecycleError::Backend(Error::SessionExpired)),
+            SessionState::AuthFailed => Err(RecycleError::Backend(Error::AuthFailed)),
+            SessionState::Closed => Err(RecycleError::Backend(Error::ClientClosed)),
+        }
+    }
+}
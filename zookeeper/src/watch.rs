@@ -0,0 +1,227 @@
+use std::{sync::Mutex, time::Duration};
+
+use tokio::sync::{broadcast, oneshot};
+use zookeeper_client::{
+    AddWatchMode, Client, Connector, Error, PersistentWatcher, StateWatcher, WatchedEvent,
+};
+
+/// Number of [`WatchedEvent`]s a receiver of [`Manager::subscribe()`] may
+/// lag behind before it misses events.
+///
+/// [`Manager::subscribe()`]: crate::Manager::subscribe
+pub const WATCH_EVENT_CAPACITY: usize = 1024;
+
+/// Delay between two attempts to create a session for registering the
+/// watches again.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Persistent watch registered by the [`Manager`](crate::Manager).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Watch {
+    /// Path of the watched node.
+    pub path: String,
+
+    /// Which nodes are watched. See [`WatchMode`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mode: WatchMode,
+}
+
+impl Watch {
+    /// Creates a new [`Watch`] for the given `path`.
+    #[must_use]
+    pub fn new<T: Into<String>>(path: T, mode: WatchMode) -> Self {
+        Self {
+            path: path.into(),
+            mode,
+        }
+    }
+}
+
+/// Possible modes of a [`Watch`].
+///
+/// This mirrors [`zookeeper_client::AddWatchMode`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WatchMode {
+    /// Watch the data, the stat and the children of the node.
+    #[default]
+    Persistent,
+
+    /// Watch the data and the stat of the node and all of its descendants.
+    PersistentRecursive,
+}
+
+impl From<WatchMode> for AddWatchMode {
+    fn from(mode: WatchMode) -> Self {
+        match mode {
+            WatchMode::Persistent => Self::Persistent,
+            WatchMode::PersistentRecursive => Self::PersistentRecursive,
+        }
+    }
+}
+
+/// Registers the configured [`Watch`]es on exactly one live session and
+/// forwards their events to the subscribers.
+///
+/// The watches are registered on a session of the pool first. Once that
+/// session terminates a background task creates a new session on its own
+/// and registers them again, so the watches don't depend on the pool
+/// creating new sessions.
+#[derive(Debug)]
+pub(crate) struct Watches {
+    watches: Vec<Watch>,
+    sender: broadcast::Sender<WatchedEvent>,
+    /// Stops the background task once the [`Watches`] are dropped.
+    _stop: oneshot::Sender<()>,
+    /// Receiver of `_stop` which is taken by the background task, i.e. it
+    /// is [`None`] once the watches are registered.
+    stopped: Mutex<Option<oneshot::Receiver<()>>>,
+}
+
+impl Watches {
+    pub(crate) fn new(watches: Vec<Watch>) -> Self {
+        let (stop, stopped) = oneshot::channel();
+        Self {
+            watches,
+            sender: broadcast::Sender::new(WATCH_EVENT_CAPACITY),
+            _stop: stop,
+            stopped: Mutex::new(Some(stopped)),
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<WatchedEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Registers the watches on the given `client` unless they are
+    /// registered already and starts the background task keeping them
+    /// registered.
+    ///
+    /// The background tasks are spawned on the current tokio runtime the
+    /// sessions are driven by anyway.
+    pub(crate) async fn register(
+        &self,
+        client: &Client,
+        reconnect: Reconnect,
+    ) -> Result<(), Error> {
+        if self.watches.is_empty() {
+            return Ok(());
+        }
+        let Some(stopped) = self.stopped.lock().unwrap().take() else {
+            return Ok(());
+        };
+        let watchers = match watch_all(client, &self.watches).await {
+            Ok(watchers) => watchers,
+            Err(e) => {
+                *self.stopped.lock().unwrap() = Some(stopped);
+                return Err(e);
+            }
+        };
+        let task = Task {
+            watches: self.watches.clone(),
+            sender: self.sender.clone(),
+            reconnect,
+        };
+        drop(tokio::spawn(task.run(
+            client.state_watcher(),
+            watchers,
+            stopped,
+        )));
+        Ok(())
+    }
+}
+
+/// Everything needed to create a new session for the watches.
+#[derive(Debug)]
+pub(crate) struct Reconnect {
+    pub(crate) connector: Connector,
+    pub(crate) connect_string: String,
+}
+
+/// Background task keeping the watches registered.
+struct Task {
+    watches: Vec<Watch>,
+    sender: broadcast::Sender<WatchedEvent>,
+    reconnect: Reconnect,
+}
+
+impl Task {
+    async fn run(
+        self,
+        mut state: StateWatcher,
+        mut watchers: Vec<PersistentWatcher>,
+        mut stopped: oneshot::Receiver<()>,
+    ) {
+        // The session created by this task. It must be kept alive for as
+        // long as the watches are registered on it.
+        let mut session = None;
+        loop {
+            for watcher in watchers.drain(..) {
+                drop(tokio::spawn(forward(watcher, self.sender.clone())));
+            }
+            tokio::select! {
+                _ = &mut stopped => return,
+                () = terminated(&mut state) => {}
+            }
+            drop(session.take());
+            loop {
+                tokio::select! {
+                    _ = &mut stopped => return,
+                    result = self.connect() => {
+                        if let Ok((client, new_watchers)) = result {
+                            state = client.state_watcher();
+                            watchers = new_watchers;
+                            session = Some(client);
+                            break;
+                        }
+                    }
+                }
+                tokio::select! {
+                    _ = &mut stopped => return,
+                    () = tokio::time::sleep(RECONNECT_DELAY) => {}
+                }
+            }
+        }
+    }
+
+    async fn connect(&self) -> Result<(Client, Vec<PersistentWatcher>), Error> {
+        let client = self
+            .reconnect
+            .connector
+            .clone()
+            .connect(&self.reconnect.connect_string)
+            .await?;
+        let watchers = watch_all(&client, &self.watches).await?;
+        Ok((client, watchers))
+    }
+}
+
+async fn watch_all(client: &Client, watches: &[Watch]) -> Result<Vec<PersistentWatcher>, Error> {
+    let mut watchers = Vec::with_capacity(watches.len());
+    for watch in watches {
+        watchers.push(client.watch(&watch.path, watch.mode.into()).await?);
+    }
+    Ok(watchers)
+}
+
+/// Waits until the session of the given `state` watcher terminated.
+async fn terminated(state: &mut StateWatcher) {
+    while !state.peek_state().is_terminated() {
+        let _ = state.changed().await;
+    }
+}
+
+/// Forwards the events of the given `watcher` until its session
+/// terminated.
+async fn forward(mut watcher: PersistentWatcher, sender: broadcast::Sender<WatchedEvent>) {
+    loop {
+        let event = watcher.changed().await;
+        let terminated = event.session_state.is_terminated();
+        // Sending only fails if there are no subscribers.
+        let _ = sender.send(event);
+        if terminated {
+            break;
+        }
+    }
+}